@@ -1,15 +1,24 @@
 use embed_resource::CompilationResult;
+use std::{env, fs, path::Path};
+
+#[path = "src/assets/sprite_compression.rs"]
+mod sprite_compression;
+
+/// The sprites, in the format described in the file itself.
+const SPRITES_SOURCE: &str = "./src/assets/sprites.txt";
+/// The number of sprites: the egg and one per species.
+const SPRITE_COUNT: usize = 387;
 
 fn main() {
     // This will be a noop if not windows.
     add_windows_icon();
+    encode_sprites();
 }
 
 /// Adds the windows icon during build.
 fn add_windows_icon() {
     println!("cargo:rerun-if-changed=./private/icon/export/windows.ico");
-    let result_icon =
-        embed_resource::compile("./private/icon/windows.rc", embed_resource::NONE);
+    let result_icon = embed_resource::compile("./private/icon/windows.rc", embed_resource::NONE);
 
     match result_icon {
         CompilationResult::Ok => {
@@ -23,3 +32,51 @@ fn add_windows_icon() {
         }
     }
 }
+
+/// Compresses the ASCII sprites into `sprites.bin` in the output directory.
+/// See `sprite_compression::compress` for the format.
+fn encode_sprites() {
+    println!("cargo:rerun-if-changed={}", SPRITES_SOURCE);
+    println!("cargo:rerun-if-changed=./src/assets/sprite_compression.rs");
+    let source = fs::read_to_string(SPRITES_SOURCE).expect("Reading the sprites failed.");
+
+    let mut sprites: Vec<Option<Vec<u8>>> = vec![None; SPRITE_COUNT];
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    let mut finish = |current: Option<(usize, Vec<&str>)>| {
+        if let Some((index, mut lines)) = current {
+            while lines.last().is_some_and(|line| line.is_empty()) {
+                lines.pop();
+            }
+            sprites[index] = Some(lines.join("\n").into_bytes());
+        }
+    };
+    for line in source.lines() {
+        if let Some(header) = line.strip_prefix('@') {
+            let index: usize = header[..3].parse().expect("Invalid sprite number.");
+            assert!(
+                index < SPRITE_COUNT,
+                "Sprite {} doesn't belong to a species.",
+                index
+            );
+            finish(current.replace((index, Vec::new())));
+        } else if !line.starts_with('#') {
+            if let Some((_, lines)) = current.as_mut() {
+                assert!(line.is_ascii(), "The sprites may only contain ASCII.");
+                lines.push(line.trim_end());
+            }
+        }
+    }
+    finish(current);
+
+    let sprites: Vec<Vec<u8>> = sprites
+        .into_iter()
+        .enumerate()
+        .map(|(index, sprite)| sprite.unwrap_or_else(|| panic!("Sprite {} is missing.", index)))
+        .collect();
+
+    let output = sprite_compression::compress(sprites);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR isn't set.");
+    fs::write(Path::new(&out_dir).join("sprites.bin"), output)
+        .expect("Writing the sprites failed.");
+}
@@ -1,7 +1,11 @@
 pub mod logo;
 pub mod mew;
 pub mod pikachu;
+#[cfg(test)]
+mod sprite_compression;
+pub mod sprites;
 
 pub use logo::*;
 pub use mew::*;
 pub use pikachu::*;
+pub use sprites::*;
@@ -0,0 +1,66 @@
+//! The compression of the sprites. The build script includes this file to write
+//! `sprites.bin`, and the tests of `sprites` use it to check the decoder against it.
+
+use std::collections::HashMap;
+
+/// The first byte that stands for a pair of bytes. The sprites are ASCII, so they don't use it.
+pub const FIRST_PAIR: usize = 0x80;
+
+/// Compresses the sprites with byte pair encoding.
+///
+/// The most common pair of bytes is replaced with an unused byte, until all 128 non-ASCII
+/// bytes are used up. The output starts with the number of pairs and the pairs themselves,
+/// followed by the end offset of each sprite as a little endian u16 and the data of the
+/// sprites.
+pub fn compress(mut sprites: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut pairs: Vec<(u8, u8)> = Vec::new();
+    while FIRST_PAIR + pairs.len() <= u8::MAX as usize {
+        let mut counts = HashMap::new();
+        for sprite in &sprites {
+            for pair in sprite.windows(2) {
+                *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+            }
+        }
+        // Ties are broken by the pair itself, so the output doesn't depend on the hash order.
+        let Some((pair, count)) = counts
+            .into_iter()
+            .max_by_key(|&(pair, count)| (count, pair))
+        else {
+            break;
+        };
+        // Replacing a pair that occurs less often doesn't save more than the table costs.
+        if count < 3 {
+            break;
+        }
+        let token = (FIRST_PAIR + pairs.len()) as u8;
+        for sprite in sprites.iter_mut() {
+            let mut replaced = Vec::with_capacity(sprite.len());
+            let mut i = 0;
+            while i < sprite.len() {
+                if i + 1 < sprite.len() && (sprite[i], sprite[i + 1]) == pair {
+                    replaced.push(token);
+                    i += 2;
+                } else {
+                    replaced.push(sprite[i]);
+                    i += 1;
+                }
+            }
+            *sprite = replaced;
+        }
+        pairs.push(pair);
+    }
+
+    let mut output = vec![pairs.len() as u8];
+    for (first, second) in &pairs {
+        output.extend([*first, *second]);
+    }
+    let mut end: u16 = 0;
+    for sprite in &sprites {
+        end += sprite.len() as u16;
+        output.extend(end.to_le_bytes());
+    }
+    for sprite in &sprites {
+        output.extend(sprite);
+    }
+    output
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+/// The sprites of `sprites.txt`, compressed by the build script.
+/// See `sprite_compression::compress` for the format.
+static SPRITES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/sprites.bin"));
+
+/// The largest width of a sprite.
+pub const SPRITE_WIDTH: u16 = 14;
+/// The largest height of a sprite.
+pub const SPRITE_HEIGHT: u16 = 5;
+
+/// The number of sprites: the egg and one per species.
+const SPRITE_COUNT: usize = 387;
+
+/// The first byte that stands for a pair of bytes.
+const FIRST_PAIR: u8 = 0x80;
+
+/// The index of the egg's sprite, in front of the species.
+const EGG: usize = 0;
+
+/// Returns the ASCII sprite of the species with the given National Pokedex number.
+pub fn sprite(national: u16) -> Option<String> {
+    match national as usize {
+        EGG => None,
+        index => decode(index),
+    }
+}
+
+/// Returns the ASCII sprite of an egg.
+pub fn egg_sprite() -> String {
+    decode(EGG).unwrap_or_default()
+}
+
+/// Returns the sprite to show for the given Pokemon, the egg's for eggs.
+pub fn pokemon_sprite(pokemon: &StoredPokemon) -> Option<String> {
+    if pokemon.is_egg {
+        Some(egg_sprite())
+    } else {
+        sprite(pokemon.species)
+    }
+}
+
+/// Decompresses the sprite with the given index.
+fn decode(index: usize) -> Option<String> {
+    decode_from(SPRITES, index)
+}
+
+/// Decompresses the sprite with the given index from the compressed sprites.
+fn decode_from(sprites: &[u8], index: usize) -> Option<String> {
+    if index >= SPRITE_COUNT {
+        return None;
+    }
+    let pair_count = *sprites.first()? as usize;
+    let pairs = sprites.get(1..1 + 2 * pair_count)?;
+    let ends = sprites.get(1 + 2 * pair_count..1 + 2 * pair_count + 2 * SPRITE_COUNT)?;
+    let data = &sprites[1 + 2 * pair_count + 2 * SPRITE_COUNT..];
+
+    let end_of = |i: usize| u16::from_le_bytes([ends[2 * i], ends[2 * i + 1]]) as usize;
+    let start = if index == 0 { 0 } else { end_of(index - 1) };
+    let compressed = data.get(start..end_of(index))?;
+
+    let mut output = Vec::with_capacity(compressed.len() * 3);
+    for &byte in compressed {
+        expand(byte, pairs, &mut output);
+    }
+    String::from_utf8(output).ok()
+}
+
+/// Appends the bytes that the given byte stands for.
+fn expand(byte: u8, pairs: &[u8], output: &mut Vec<u8>) {
+    if byte < FIRST_PAIR {
+        output.push(byte);
+        return;
+    }
+    let pair = 2 * (byte - FIRST_PAIR) as usize;
+    // Pairs only refer to pairs that were added before them, so this always ends.
+    expand(pairs[pair], pairs, output);
+    expand(pairs[pair + 1], pairs, output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::sprite_compression::compress;
+
+    /// Returns made up sprites that repeat a lot, like the real ones.
+    fn sample_sprites() -> Vec<Vec<u8>> {
+        (0..SPRITE_COUNT)
+            .map(|i| format!(" /\\_/\\ {}\n( o.o )\n > ^ <{}", i, "~".repeat(i % 7)).into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn decodes_what_is_compressed() {
+        let sprites = sample_sprites();
+        let compressed = compress(sprites.clone());
+        assert!(compressed.len() < sprites.iter().map(Vec::len).sum::<usize>());
+        assert!(compressed[0] as usize <= 0x100 - FIRST_PAIR as usize);
+        for (index, sprite) in sprites.iter().enumerate() {
+            assert_eq!(
+                decode_from(&compressed, index).unwrap().as_bytes(),
+                sprite.as_slice()
+            );
+        }
+        assert!(decode_from(&compressed, SPRITE_COUNT).is_none());
+    }
+
+    #[test]
+    fn expands_nested_pairs() {
+        // 0x81 stands for 0x80 and 'c', 0x80 for "ab".
+        let pairs = [b'a', b'b', FIRST_PAIR, b'c'];
+        let mut output = Vec::new();
+        expand(FIRST_PAIR + 1, &pairs, &mut output);
+        assert_eq!(output, b"abc");
+    }
+
+    #[test]
+    fn has_a_sprite_for_every_species() {
+        for national in 1..=NATIONAL_DEX_SIZE as u16 {
+            let drawn = sprite(national).unwrap();
+            assert!(!drawn.is_empty(), "species {}", national);
+            assert!(drawn.lines().count() <= SPRITE_HEIGHT as usize);
+            assert!(drawn
+                .lines()
+                .all(|line| line.len() <= SPRITE_WIDTH as usize));
+        }
+        assert!(!egg_sprite().is_empty());
+        assert!(sprite(0).is_none());
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
 
+use crate::i18n::{tr, trf, Text};
+use crate::saves::save_extension_list;
 use thiserror::Error;
 
 pub type Result<T> = core::result::Result<T, AppError>;
@@ -8,28 +10,260 @@ pub type Result<T> = core::result::Result<T, AppError>;
 #[derive(Debug, Error)]
 pub enum AppError {
     #[cfg(feature = "calculator-build")]
-    #[error("You need at least two save files with the extension '.sav'. Place them in the top level directory.")]
+    #[error("You need at least two Gen 3 save files ({}). Place them in the top level directory or choose another save folder in the settings.", save_extension_list())]
     MissingFiles,
 
     #[cfg(any(target_os = "windows", target_os = "macos"))]
-    #[error("You need at least two save files with the extension '.sav'. Place them on your desktop.")]
+    #[error("You need at least two Gen 3 save files ({}). Place them on your desktop or choose another save folder in the settings.", save_extension_list())]
     MissingFiles,
 
     #[cfg(target_os = "linux")]
-    #[error("You need at least two save files with the extension '.sav'. Place them in your home directory, for example ~/emerald.sav")]
+    #[error("You need at least two Gen 3 save files ({}). Place them in your home directory, for example ~/emerald.sav, or choose another save folder in the settings.", save_extension_list())]
     MissingFiles,
 
-    #[error("Filesystem error: {0}")]
-    FsError(String),
+    #[error("Failed to {operation} {path}: {kind}")]
+    FsError {
+        /// What was done with the file, e.g. "read" or "write".
+        operation: &'static str,
+        path: String,
+        /// The kind of the error reported by the operating system.
+        kind: String,
+    },
+
+    #[error("The settings file is from a newer version of nTrade Advance and is left unchanged.")]
+    NewerSettings,
+
+    #[error("Failed to parse save file {0}.")]
+    ParseFailed(String),
+
+    #[error("Failed to read the sectors of save file {0}.")]
+    InvalidSectors(String),
+
+    #[error("Failed to serialize save file {0}. Don't worry, nothing has been written yet.")]
+    SerializeFailed(String),
+
+    #[error("Failed to write save file {save}. Data might be corrupt now.")]
+    WriteFailed {
+        save: String,
+        /// Saves that were already written as part of the same change, e.g. the other save
+        /// of a trade. Their backups can only be restored together with this one.
+        written: Vec<String>,
+        source: Box<AppError>,
+    },
+
+    #[error("The trade was only written to save file {written}, {failed} still has the Pokemon it sent.")]
+    TradeIncomplete {
+        /// The save that already has the trade.
+        written: String,
+        /// The save that couldn't be written. Nothing was written to it.
+        failed: String,
+        source: Box<AppError>,
+    },
+
+    #[error("Pokemon {slot} in the party of {save} has an unknown species and can't be added to the Pokedex.")]
+    UnknownSpecies {
+        /// The name of the trainer.
+        save: String,
+        /// The party slot, starting at 1.
+        slot: usize,
+    },
 
     #[error("Pokemon not found.")]
     PokemonNotFound,
 
+    #[error("Bad eggs can't be traded. Their data is corrupted and could damage the other save.")]
+    BadEgg,
+
+    #[error("{0} has no free mail slot for the mail of the received Pokemon. Take the mail from one of its Pokemon first.")]
+    NoMailRoom(String),
+
+    #[error("The new save {0} failed its check, nothing has been written. {1}")]
+    VerificationFailed(String, String),
+
+    #[error(
+        "Failed to move the mail of save file {0}. Don't worry, nothing has been written yet."
+    )]
+    MailFailed(String),
+
+    #[error("The saves of the trade screen aren't loaded. Open the trade screen again.")]
+    SavesNotLoaded,
+
+    #[error("'{0}' is not a valid nickname.")]
+    InvalidNickname(String),
+
+    #[cfg(not(feature = "calculator-build"))]
+    #[error("Terminal error: {0}")]
+    Terminal(String),
+
     #[error("Save error: {0}")]
     SaveError(pkmn_savedata::SaveError),
 
-    #[error("{0}")]
-    Custom(String),
+    /// Describes what was being done when the source error happened.
+    #[error("{context}")]
+    Context {
+        context: String,
+        source: Box<AppError>,
+    },
+}
+
+impl AppError {
+    /// A stable number that identifies the kind of error in bug reports.
+    /// Codes are never reused: 1xx are files, 2xx saves, 3xx trades and 9xx everything else.
+    pub fn code(&self) -> u16 {
+        match self {
+            AppError::MissingFiles => 101,
+            AppError::FsError { .. } => 102,
+            AppError::NewerSettings => 103,
+            AppError::SaveError(_) => 201,
+            AppError::ParseFailed(_) => 202,
+            AppError::InvalidSectors(_) => 203,
+            AppError::SerializeFailed(_) => 204,
+            AppError::UnknownSpecies { .. } => 205,
+            AppError::WriteFailed { .. } => 206,
+            AppError::InvalidNickname(_) => 207,
+            AppError::PokemonNotFound => 301,
+            AppError::BadEgg => 302,
+            AppError::NoMailRoom(_) => 303,
+            AppError::VerificationFailed(..) => 304,
+            AppError::MailFailed(_) => 305,
+            AppError::SavesNotLoaded => 306,
+            AppError::TradeIncomplete { .. } => 307,
+            #[cfg(not(feature = "calculator-build"))]
+            AppError::Terminal(_) => 901,
+            AppError::Context { source, .. } => source.code(),
+        }
+    }
+
+    /// The message shown to the user, in the language of the user interface.
+    /// `Display` stays in English for bug reports.
+    pub fn message(&self) -> String {
+        match self {
+            #[cfg(feature = "calculator-build")]
+            AppError::MissingFiles => trf(Text::MissingFilesCalculator, &[&save_extension_list()]),
+            #[cfg(any(target_os = "windows", target_os = "macos"))]
+            AppError::MissingFiles => trf(Text::MissingFilesDesktop, &[&save_extension_list()]),
+            #[cfg(target_os = "linux")]
+            AppError::MissingFiles => trf(Text::MissingFilesLinux, &[&save_extension_list()]),
+            AppError::FsError { path, kind, .. } => trf(Text::FileError, &[path, kind]),
+            AppError::NewerSettings => tr(Text::NewerSettings).to_string(),
+            AppError::ParseFailed(save) => trf(Text::ParseFailed, &[save]),
+            AppError::InvalidSectors(save) => trf(Text::InvalidSectors, &[save]),
+            AppError::SerializeFailed(save) => trf(Text::SerializeFailed, &[save]),
+            AppError::WriteFailed { save, .. } => trf(Text::WriteFailed, &[save]),
+            AppError::TradeIncomplete {
+                written, failed, ..
+            } => trf(Text::TradeIncomplete, &[written, failed]),
+            AppError::UnknownSpecies { save, slot } => {
+                trf(Text::UnknownSpecies, &[&slot.to_string(), save])
+            }
+            AppError::PokemonNotFound => tr(Text::PokemonNotFound).to_string(),
+            AppError::BadEgg => tr(Text::BadEggTrade).to_string(),
+            AppError::NoMailRoom(save) => trf(Text::NoMailRoom, &[save]),
+            AppError::VerificationFailed(save, reason) => {
+                trf(Text::VerificationFailed, &[save, reason])
+            }
+            AppError::MailFailed(save) => trf(Text::MailFailed, &[save]),
+            AppError::SavesNotLoaded => tr(Text::SavesNotLoaded).to_string(),
+            AppError::InvalidNickname(nickname) => trf(Text::InvalidNickname, &[nickname]),
+            // Contexts are translated where they are added.
+            AppError::Context { context, .. } => context.clone(),
+            error => error.to_string(),
+        }
+    }
+
+    /// Returns the error that caused this one, without its context.
+    pub fn root(&self) -> &AppError {
+        match self {
+            AppError::Context { source, .. }
+            | AppError::WriteFailed { source, .. }
+            | AppError::TradeIncomplete { source, .. } => source.root(),
+            error => error,
+        }
+    }
+
+    /// Returns the context of the error, from the outermost to the innermost.
+    pub fn contexts(&self) -> Vec<String> {
+        let mut contexts = Vec::new();
+        let mut error = self;
+        loop {
+            match error {
+                AppError::Context { context, source } => {
+                    contexts.push(context.clone());
+                    error = source;
+                }
+                AppError::WriteFailed { source, .. } | AppError::TradeIncomplete { source, .. } => {
+                    contexts.push(error.to_string());
+                    error = source;
+                }
+                _ => return contexts,
+            }
+        }
+    }
+
+    /// Returns the ways out of this error that the error screen offers.
+    pub fn recoveries(&self) -> Vec<Recovery> {
+        match self {
+            AppError::MissingFiles => {
+                vec![Recovery::Retry, Recovery::ChooseOtherFile, Recovery::Exit]
+            }
+            AppError::FsError { .. } | AppError::ParseFailed(_) | AppError::InvalidSectors(_) => {
+                vec![Recovery::Retry, Recovery::ChooseOtherFile]
+            }
+            AppError::WriteFailed { save, written, .. } => {
+                let mut saves = vec![save.clone()];
+                saves.extend(written.iter().cloned());
+                vec![Recovery::RestoreBackup(saves), Recovery::Exit]
+            }
+            AppError::TradeIncomplete { written, .. } => {
+                vec![
+                    Recovery::RestoreBackup(vec![written.clone()]),
+                    Recovery::Exit,
+                ]
+            }
+            AppError::Context { source, .. } => source.recoveries(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A way out of an error, offered as a button on the error screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Recovery {
+    /// Opens the screen that failed to open again.
+    Retry,
+    /// Lets the user pick another save folder and opens the screen that failed again.
+    ChooseOtherFile,
+    /// Writes the backups of the saves with the given names back over them. They are only
+    /// restored together, restoring one save of a trade would clone or lose a Pokemon.
+    RestoreBackup(Vec<String>),
+    /// Closes the program.
+    Exit,
+}
+
+impl Recovery {
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            Recovery::Retry => Text::Retry,
+            Recovery::ChooseOtherFile => Text::OtherFolder,
+            Recovery::RestoreBackup(_) => Text::RestoreBackup,
+            Recovery::Exit => Text::Exit,
+        })
+    }
+}
+
+/// Adds context to the error of a result. The error screen shows the outermost context
+/// and lists the rest in its details.
+pub trait ResultExt<T> {
+    fn with_context<S: Into<String>, F: FnOnce() -> S>(self, context: F) -> Result<T>;
+}
+
+impl<T, E: Into<AppError>> ResultExt<T> for core::result::Result<T, E> {
+    fn with_context<S: Into<String>, F: FnOnce() -> S>(self, context: F) -> Result<T> {
+        self.map_err(|e| AppError::Context {
+            context: context().into(),
+            source: Box::new(e.into()),
+        })
+    }
 }
 
 impl From<pkmn_savedata::SaveError> for AppError {
@@ -0,0 +1,374 @@
+use crate::prelude::*;
+
+/// A language of the user interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Language {
+    /// The languages in the order of the columns of the string table.
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::German,
+        Language::French,
+        Language::Spanish,
+    ];
+
+    /// The name of the language in itself, as shown in the settings.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+            Language::French => "Francais",
+            Language::Spanish => "Espanol",
+        }
+    }
+
+    /// The code that is stored in the settings file.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Spanish => "es",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Language> {
+        Language::ALL.into_iter().find(|l| l.code() == code)
+    }
+
+    /// Converts the language id that the games store in every Pokemon.
+    /// Languages without a translation return None.
+    fn from_game(id: u8) -> Option<Language> {
+        match id {
+            2 => Some(Language::English),
+            3 => Some(Language::French),
+            5 => Some(Language::German),
+            7 => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+}
+
+/// Declares the `Text` keys together with their translations, in the order of `Language::ALL`.
+macro_rules! strings {
+    ($($key:ident: $en:literal, $de:literal, $fr:literal, $es:literal;)*) => {
+        /// A translated string of the user interface. Placeholders (`{}`) are filled in by `trf`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Text {
+            $($key,)*
+        }
+
+        const STRINGS: &[[&str; 4]] = &[$([$en, $de, $fr, $es],)*];
+    };
+}
+
+// The console only shows ASCII, so umlauts and accents are spelled out or left off.
+// Labels of buttons and lists have to fit the widths of the screens, see the comments.
+strings! {
+    // Menu. Buttons fit 14 characters.
+    MenuTagline1: "Trade Pokemon between Ruby, Sapphire,", "Tausche Pokemon zwischen Rubin, Saphir,", "Echange des Pokemon entre Rubis, Saphir,", "Intercambia Pokemon entre Rubi, Zafiro,";
+    MenuTagline2: "Emerald, FireRed & LeafGreen!", "Smaragd, Feuerrot & Blattgruen!", "Emeraude, Rouge Feu & Vert Feuille !", "Esmeralda, Rojo Fuego y Verde Hoja!";
+    Trade: "Trade", "Tauschen", "Echanger", "Intercambiar";
+    Settings: "Settings", "Optionen", "Options", "Opciones";
+    About: "About", "Info", "A propos", "Acerca de";
+    Exit: "Exit", "Beenden", "Quitter", "Salir";
+
+    // Used on several screens. Buttons fit 7 characters.
+    Back: "Back", "Zurueck", "Retour", "Atras";
+    Cancel: "Cancel", "Abbruch", "Annuler", "Cancelar";
+    Yes: "Yes", "Ja", "Oui", "Si";
+    No: "No", "Nein", "Non", "No";
+    Any: "Any", "Alle", "Tous", "Todos";
+    Egg: "Egg", "Ei", "Oeuf", "Huevo";
+    BadEgg: "Bad Egg", "Schlechtes Ei", "Mauvais Oeuf", "Huevo malo";
+    Party: "Party", "Team", "Equipe", "Equipo";
+    BoxNumber: "Box {}", "Box {}", "Boite {}", "Caja {}";
+
+    // Settings. Names fit 12 characters and values 15.
+    TextColor: "Text color", "Textfarbe", "Couleur", "Color texto";
+    Animations: "Animations", "Animationen", "Animations", "Animaciones";
+    Backups: "Backups", "Backups", "Sauvegardes", "Copias";
+    LanguageSetting: "Language", "Sprache", "Langue", "Idioma";
+    AutoLanguage: "Auto ({})", "Auto ({})", "Auto ({})", "Auto ({})";
+    SaveFolder: "Save folder:", "Spielstand-Ordner:", "Dossier des sauvegardes :", "Carpeta de partidas:";
+    WithSubfolders: "{} (+ subfolders)", "{} (+ Unterordner)", "{} (+ sous-dossiers)", "{} (+ subcarpetas)";
+    ChangeFolder: "Change folder", "Ordner waehlen", "Changer dossier", "Cambiar carpeta";
+    SettingsNotSaved: "The settings couldn't be saved: {}", "Die Optionen konnten nicht gespeichert werden: {}", "Les options n'ont pas pu etre enregistrees : {}", "No se pudieron guardar las opciones: {}";
+    ColorCustom: "Custom", "Eigene", "Perso", "Propio";
+    ColorWhite: "White", "Weiss", "Blanc", "Blanco";
+    ColorGreen: "Green", "Gruen", "Vert", "Verde";
+    ColorCyan: "Cyan", "Cyan", "Cyan", "Cian";
+    ColorYellow: "Yellow", "Gelb", "Jaune", "Amarillo";
+    ColorMagenta: "Magenta", "Magenta", "Magenta", "Magenta";
+    SpeedSlow: "Slow", "Langsam", "Lent", "Lento";
+    SpeedNormal: "Normal", "Normal", "Normal", "Normal";
+    SpeedFast: "Fast", "Schnell", "Rapide", "Rapido";
+    SpeedOff: "Off", "Aus", "Desactive", "Desactivado";
+    BackupOff: "Off", "Aus", "Desactive", "Desactivado";
+    BackupOriginal: "Keep original", "Nur Original", "Original seul", "Solo original";
+    BackupEveryWrite: "Every write", "Jedes Mal", "A chaque fois", "Cada vez";
+
+    // Save folder. Buttons fit 8 characters, the subfolder switch 17.
+    ChooseSaveFolder: "Choose the save folder", "Spielstand-Ordner waehlen", "Choisir le dossier des sauvegardes", "Elige la carpeta de partidas";
+    SubfoldersOn: "Subfolders: on", "Unterordner: an", "Sous-dossier: oui", "Subcarpetas: si";
+    SubfoldersOff: "Subfolders: off", "Unterordner: aus", "Sous-dossier: non", "Subcarpetas: no";
+    Use: "Use", "Nutzen", "Utiliser", "Usar";
+
+    // About.
+    MadeIn: "Made in Germany in 2025", "Entwickelt in Deutschland, 2025", "Fait en Allemagne en 2025", "Hecho en Alemania en 2025";
+    Warranty: "This application is provided without any warranty. Make sure you have backups of your save files before using it!", "Diese Anwendung wird ohne jegliche Garantie bereitgestellt. Lege Sicherungen deiner Spielstaende an, bevor du sie benutzt!", "Cette application est fournie sans aucune garantie. Faites des copies de vos sauvegardes avant de l'utiliser !", "Esta aplicacion se ofrece sin ninguna garantia. Haz copias de tus partidas antes de usarla!";
+    History1: "The original version from 2014 was written", "Die Originalversion von 2014 war in C", "La version originale de 2014 etait en C,", "La version original de 2014 era en C,";
+    History2: "in c, v2.0.0 onwards is written in Rust.", "geschrieben, ab v2.0.0 ist sie in Rust.", "depuis la v2.0.0 elle est ecrite en Rust.", "desde la v2.0.0 esta escrita en Rust.";
+    SpecialThanks: "Special thanks to:", "Besonderer Dank an:", "Remerciements :", "Agradecimientos:";
+    ThanksTo: "- {} by {}", "- {} von {}", "- {} par {}", "- {} por {}";
+    NdlessTeam: "the Ndless team", "dem Ndless-Team", "l'equipe Ndless", "el equipo de Ndless";
+
+    // Trade. Buttons fit 7 characters.
+    Trading: "Trading", "Tausch", "Echange", "Intercambio";
+    TradeButton: "Trade", "Tausch", "Echange", "Cambiar";
+    SearchButton: "Search", "Suchen", "Trouver", "Buscar";
+    RenameButton: "Rename", "Name", "Nommer", "Mote";
+    PokedexButton: "Pokedex", "Pokedex", "Pokedex", "Pokedex";
+    TradeInProgress: "Trading...", "Tausche...", "Echange en cours...", "Intercambiando...";
+    TradeSuccessful: "Trade successful!", "Tausch erfolgreich!", "Echange reussi !", "Intercambio completado!";
+    SkipAnimation: "Press any key to skip.", "Beliebige Taste zum Ueberspringen.", "Appuyez sur une touche pour passer.", "Pulsa una tecla para saltar.";
+    EvolvesInGame: "{} evolves the next time it is traded in-game.", "{} entwickelt sich beim naechsten Tausch im Spiel.", "{} evoluera lors du prochain echange en jeu.", "{} evolucionara en el proximo intercambio en el juego.";
+    TradeQuestion: "Trade {} for {}?", "{} gegen {} tauschen?", "Echanger {} contre {} ?", "Cambiar {} por {}?";
+    SelectEach: "Please select a pokemon from each trainer.", "Bitte waehle ein Pokemon von jedem Trainer.", "Choisissez un Pokemon de chaque dresseur.", "Elige un Pokemon de cada entrenador.";
+    SelectToRename: "Please select the pokemon to rename.", "Bitte waehle das Pokemon, das umbenannt werden soll.", "Choisissez le Pokemon a renommer.", "Elige el Pokemon que quieres renombrar.";
+    EggRename: "Eggs can't be renamed until they hatch.", "Eier koennen erst nach dem Schluepfen umbenannt werden.", "Les oeufs ne peuvent pas etre renommes avant d'eclore.", "Los huevos no se pueden renombrar hasta que eclosionen.";
+    Renamed: "Renamed to {}.", "Umbenannt in {}.", "Renomme en {}.", "Renombrado a {}.";
+
+    // Nickname.
+    Nickname: "Nickname", "Spitzname", "Surnom", "Mote";
+    Save: "Save", "Sichern", "Sauver", "Guardar";
+    EnterNickname: "Please enter a nickname.", "Bitte gib einen Spitznamen ein.", "Entrez un surnom.", "Escribe un mote.";
+    NicknameTooLong: "Nicknames can't be longer than {} characters.", "Spitznamen duerfen hoechstens {} Zeichen lang sein.", "Les surnoms ne peuvent pas depasser {} caracteres.", "Los motes no pueden tener mas de {} caracteres.";
+    NicknameFiltered: "Characters of {} that can't be typed were removed.", "Nicht eingebbare Zeichen von {} wurden entfernt.", "Les caracteres de {} non saisissables ont ete retires.", "Se quitaron los caracteres de {} que no se pueden escribir.";
+
+    // Pokedex.
+    DexNothing: "{} has caught nothing that {} needs.", "{} hat nichts gefangen, was {} fehlt.", "{} n'a rien attrape dont {} a besoin.", "{} no ha capturado nada que le falte a {}.";
+    DexOneGap: "{} can fill 1 gap in the Pokedex of {}.", "{} kann 1 Luecke im Pokedex von {} fuellen.", "{} peut combler 1 trou dans le Pokedex de {}.", "{} puede llenar 1 hueco en la Pokedex de {}.";
+    DexGaps: "{} can fill {} gaps in the Pokedex of {}.", "{} kann {} Luecken im Pokedex von {} fuellen.", "{} peut combler {} trous dans le Pokedex de {}.", "{} puede llenar {} huecos en la Pokedex de {}.";
+    NeededBy: "Needed by {}", "Fehlt {}", "Manque a {}", "Le falta a {}";
+    NoneLeft: "(none left)", "(keins mehr)", "(plus aucun)", "(ya no queda)";
+
+    // Search. Criteria fit 8 characters and places 7.
+    SearchTitle: "Search ({} found)", "Suche ({} gefunden)", "Recherche ({} trouves)", "Busqueda ({} encontrados)";
+    PartySlot: "Party {}", "Team {}", "Eq. {}", "Eq. {}";
+    BoxSlot: "Box {}/{}", "Box {}/{}", "Bte {}/{}", "Caja {}/{}";
+    InBox: "{} is in box {} of {}. Move it to the party in-game to trade it.", "{} ist in Box {} von {}. Nimm es im Spiel ins Team, um es zu tauschen.", "{} est dans la boite {} de {}. Mettez-le dans l'equipe en jeu pour l'echanger.", "{} esta en la caja {} de {}. Ponlo en el equipo en el juego para cambiarlo.";
+    CriterionName: "Name", "Name", "Nom", "Nombre";
+    CriterionType: "Type", "Typ", "Type", "Tipo";
+    CriterionLevel: "Level", "Level", "Niveau", "Nivel";
+    CriterionShiny: "Shiny", "Shiny", "Shiny", "Vario";
+    CriterionItem: "Item", "Item", "Objet", "Objeto";
+    CriterionOt: "OT", "OT", "DO", "EO";
+    CriterionNature: "Nature", "Wesen", "Nature", "Natural.";
+    // Boxes don't store the level, so the level filter only finds party Pokemon. Fits 14 characters.
+    PartyLevels: "{} (party)", "{} (Team)", "{} (eq.)", "{} (eq.)";
+    Clear: "Clear", "Leeren", "Effacer", "Borrar";
+
+    // Trainer card. Names fit 12 characters.
+    TrainerCard: "Trainer card", "Trainerpass", "Carte dresseur", "Ficha entrenador";
+    CardGame: "Game", "Spiel", "Jeu", "Juego";
+    CardName: "Name", "Name", "Nom", "Nombre";
+    CardGender: "Gender", "Geschlecht", "Sexe", "Sexo";
+    Girl: "Girl", "Maedchen", "Fille", "Chica";
+    Boy: "Boy", "Junge", "Garcon", "Chico";
+    CardId: "ID No.", "ID-Nr.", "No ID", "N. ID";
+    CardSecretId: "Secret ID", "Geheim-ID", "ID secret", "ID secreto";
+    CardPlayTime: "Play time", "Spielzeit", "Temps de jeu", "Tiempo";
+    CardMoney: "Money", "Geld", "Argent", "Dinero";
+    CardBadges: "Badges", "Orden", "Badges", "Medallas";
+    CardDex: "{} seen, {} caught", "{} gesehen, {} gefangen", "{} vus, {} attrapes", "{} vistos, {} capturados";
+    CardHallOfFame: "Hall of Fame", "Ruhmeshalle", "Pantheon", "Salon Fama";
+
+    // Types, natures and games.
+    TypeNormal: "Normal", "Normal", "Normal", "Normal";
+    TypeFire: "Fire", "Feuer", "Feu", "Fuego";
+    TypeWater: "Water", "Wasser", "Eau", "Agua";
+    TypeElectric: "Electric", "Elektro", "Electrik", "Electrico";
+    TypeGrass: "Grass", "Pflanze", "Plante", "Planta";
+    TypeIce: "Ice", "Eis", "Glace", "Hielo";
+    TypeFighting: "Fighting", "Kampf", "Combat", "Lucha";
+    TypePoison: "Poison", "Gift", "Poison", "Veneno";
+    TypeGround: "Ground", "Boden", "Sol", "Tierra";
+    TypeFlying: "Flying", "Flug", "Vol", "Volador";
+    TypePsychic: "Psychic", "Psycho", "Psy", "Psiquico";
+    TypeBug: "Bug", "Kaefer", "Insecte", "Bicho";
+    TypeRock: "Rock", "Gestein", "Roche", "Roca";
+    TypeGhost: "Ghost", "Geist", "Spectre", "Fantasma";
+    TypeDragon: "Dragon", "Drache", "Dragon", "Dragon";
+    TypeDark: "Dark", "Unlicht", "Tenebres", "Siniestro";
+    TypeSteel: "Steel", "Stahl", "Acier", "Acero";
+    NatureHardy: "Hardy", "Robust", "Hardi", "Fuerte";
+    NatureLonely: "Lonely", "Solo", "Solo", "Hurana";
+    NatureBrave: "Brave", "Mutig", "Brave", "Audaz";
+    NatureAdamant: "Adamant", "Hart", "Rigide", "Firme";
+    NatureNaughty: "Naughty", "Frech", "Mauvais", "Picara";
+    NatureBold: "Bold", "Kuehn", "Assure", "Osada";
+    NatureDocile: "Docile", "Sanft", "Docile", "Docil";
+    NatureRelaxed: "Relaxed", "Locker", "Relax", "Placida";
+    NatureImpish: "Impish", "Pfiffig", "Malin", "Agitada";
+    NatureLax: "Lax", "Lasch", "Lache", "Floja";
+    NatureTimid: "Timid", "Scheu", "Timide", "Miedosa";
+    NatureHasty: "Hasty", "Hastig", "Presse", "Activa";
+    NatureSerious: "Serious", "Ernst", "Serieux", "Seria";
+    NatureJolly: "Jolly", "Froh", "Jovial", "Alegre";
+    NatureNaive: "Naive", "Naiv", "Naif", "Ingenua";
+    NatureModest: "Modest", "Maessig", "Modeste", "Modesta";
+    NatureMild: "Mild", "Mild", "Doux", "Afable";
+    NatureQuiet: "Quiet", "Ruhig", "Discret", "Mansa";
+    NatureBashful: "Bashful", "Zaghaft", "Pudique", "Timida";
+    NatureRash: "Rash", "Hitzig", "Foufou", "Alocada";
+    NatureCalm: "Calm", "Still", "Calme", "Serena";
+    NatureGentle: "Gentle", "Zart", "Gentil", "Amable";
+    NatureSassy: "Sassy", "Forsch", "Malpoli", "Grosera";
+    NatureCareful: "Careful", "Sacht", "Prudent", "Cauta";
+    NatureQuirky: "Quirky", "Kauzig", "Bizarre", "Rara";
+    GameRubySapphire: "Ruby/Sapphire", "Rubin/Saphir", "Rubis/Saphir", "Rubi/Zafiro";
+    GameFireRedLeafGreen: "FireRed/LeafGreen", "Feuerrot/Blattgruen", "Rouge Feu/Vert Feuille", "Rojo Fuego/Verde Hoja";
+    GameEmerald: "Emerald", "Smaragd", "Emeraude", "Esmeralda";
+
+    // Errors. Buttons fit 14 characters.
+    ErrorTitle: "ERROR", "FEHLER", "ERREUR", "ERROR";
+    Details: "Details", "Details", "Details", "Detalles";
+    Hide: "Hide", "Weniger", "Masquer", "Ocultar";
+    Retry: "Retry", "Nochmal", "Reessayer", "Reintentar";
+    OtherFolder: "Other folder", "Anderer Ordner", "Autre dossier", "Otra carpeta";
+    RestoreBackup: "Restore backup", "Sicherung laden", "Restaurer copie", "Restaurar copia";
+    BackupRestored: "The backup of {} has been restored.", "Die Sicherung von {} wurde wiederhergestellt.", "La copie de {} a ete restauree.", "Se ha restaurado la copia de {}.";
+    BackupAge: "The backup of {} is from play time {}, {} saves ago.", "Die Sicherung von {} ist von Spielzeit {}, vor {} Speicherungen.", "La copie de {} date du temps de jeu {}, il y a {} sauvegardes.", "La copia de {} es del tiempo de juego {}, hace {} guardados.";
+    BackupPlayTime: "The backup of {} is from play time {}.", "Die Sicherung von {} ist von Spielzeit {}.", "La copie de {} date du temps de jeu {}.", "La copia de {} es del tiempo de juego {}.";
+    RestoreQuestion: "Restore now? Newer progress is lost.", "Jetzt wiederherstellen? Neuerer Fortschritt geht verloren.", "Restaurer maintenant ? Les progres recents seront perdus.", "Restaurar ahora? Se perdera el progreso reciente.";
+    MissingFilesCalculator: "You need at least two Gen 3 save files ({}). Place them in the top level directory or choose another save folder in the settings.", "Du brauchst mindestens zwei Spielstaende der 3. Generation ({}). Lege sie im obersten Ordner ab oder waehle in den Optionen einen anderen Ordner.", "Il faut au moins deux sauvegardes de 3e generation ({}). Placez-les dans le dossier racine ou choisissez un autre dossier dans les options.", "Necesitas al menos dos partidas de 3a generacion ({}). Ponlas en la carpeta principal o elige otra carpeta en las opciones.";
+    MissingFilesDesktop: "You need at least two Gen 3 save files ({}). Place them on your desktop or choose another save folder in the settings.", "Du brauchst mindestens zwei Spielstaende der 3. Generation ({}). Lege sie auf den Desktop oder waehle in den Optionen einen anderen Ordner.", "Il faut au moins deux sauvegardes de 3e generation ({}). Placez-les sur le bureau ou choisissez un autre dossier dans les options.", "Necesitas al menos dos partidas de 3a generacion ({}). Ponlas en el escritorio o elige otra carpeta en las opciones.";
+    MissingFilesLinux: "You need at least two Gen 3 save files ({}). Place them in your home directory, for example ~/emerald.sav, or choose another save folder in the settings.", "Du brauchst mindestens zwei Spielstaende der 3. Generation ({}). Lege sie in dein Home-Verzeichnis, zum Beispiel ~/emerald.sav, oder waehle in den Optionen einen anderen Ordner.", "Il faut au moins deux sauvegardes de 3e generation ({}). Placez-les dans votre dossier personnel, par exemple ~/emerald.sav, ou choisissez un autre dossier dans les options.", "Necesitas al menos dos partidas de 3a generacion ({}). Ponlas en tu carpeta personal, por ejemplo ~/emerald.sav, o elige otra carpeta en las opciones.";
+    FileError: "Failed to access {}: {}", "Auf {} konnte nicht zugegriffen werden: {}", "Impossible d'acceder a {} : {}", "No se pudo acceder a {}: {}";
+    ParseFailed: "Failed to parse save file {}.", "Spielstand {} konnte nicht gelesen werden.", "La sauvegarde {} n'a pas pu etre lue.", "No se pudo leer la partida {}.";
+    NewerSettings: "The settings file is from a newer version of nTrade Advance and is left unchanged.", "Die Optionsdatei stammt von einer neueren Version von nTrade Advance und bleibt unveraendert.", "Le fichier d'options vient d'une version plus recente de nTrade Advance et reste inchange.", "El archivo de opciones es de una version mas reciente de nTrade Advance y no se modifica.";
+    InvalidSectors: "Failed to read the sectors of save file {}.", "Die Sektoren von Spielstand {} konnten nicht gelesen werden.", "Les secteurs de la sauvegarde {} n'ont pas pu etre lus.", "No se pudieron leer los sectores de la partida {}.";
+    SerializeFailed: "Failed to serialize save file {}. Don't worry, nothing has been written yet.", "Spielstand {} konnte nicht erstellt werden. Keine Sorge, es wurde noch nichts geschrieben.", "La sauvegarde {} n'a pas pu etre generee. Pas d'inquietude, rien n'a encore ete ecrit.", "No se pudo generar la partida {}. Tranquilo, aun no se ha escrito nada.";
+    WriteFailed: "Failed to write save file {}. Data might be corrupt now.", "Spielstand {} konnte nicht geschrieben werden. Die Daten koennten jetzt beschaedigt sein.", "La sauvegarde {} n'a pas pu etre ecrite. Les donnees sont peut-etre corrompues.", "No se pudo escribir la partida {}. Los datos podrian estar danados.";
+    UnknownSpecies: "Pokemon {} in the party of {} has an unknown species and can't be added to the Pokedex.", "Pokemon {} im Team von {} hat eine unbekannte Art und kann nicht in den Pokedex eingetragen werden.", "Le Pokemon {} de l'equipe de {} est d'une espece inconnue et ne peut pas etre ajoute au Pokedex.", "El Pokemon {} del equipo de {} es de una especie desconocida y no se puede anadir a la Pokedex.";
+    PokemonNotFound: "Pokemon not found.", "Pokemon nicht gefunden.", "Pokemon introuvable.", "No se encontro el Pokemon.";
+    BadEggTrade: "Bad eggs can't be traded. Their data is corrupted and could damage the other save.", "Schlechte Eier koennen nicht getauscht werden. Ihre Daten sind beschaedigt und koennten den anderen Spielstand beschaedigen.", "Les mauvais oeufs ne peuvent pas etre echanges. Leurs donnees sont corrompues et pourraient endommager l'autre sauvegarde.", "Los huevos malos no se pueden intercambiar. Sus datos estan danados y podrian danar la otra partida.";
+    NoMailRoom: "{} has no free mail slot for the mail of the received Pokemon. Take the mail from one of its Pokemon first.", "{} hat keinen freien Platz fuer den Brief des erhaltenen Pokemon. Nimm zuerst einem Pokemon seinen Brief ab.", "{} n'a pas de place pour la lettre du Pokemon recu. Retirez d'abord la lettre d'un de ses Pokemon.", "{} no tiene hueco para la carta del Pokemon recibido. Quita primero la carta a uno de sus Pokemon.";
+    VerificationFailed: "The new save {} failed its check, nothing has been written. {}", "Der neue Spielstand {} hat die Pruefung nicht bestanden, es wurde nichts geschrieben. {}", "La nouvelle sauvegarde {} n'a pas passe la verification, rien n'a ete ecrit. {}", "La nueva partida {} no ha pasado la comprobacion, no se ha escrito nada. {}";
+    VerifyOriginalUnreadable: "The save file on disk can't be read anymore.", "Der Spielstand auf dem Datentraeger kann nicht mehr gelesen werden.", "La sauvegarde sur le disque ne peut plus etre lue.", "La partida del disco ya no se puede leer.";
+    VerifyNewUnreadable: "The new save can't be read back.", "Der neue Spielstand kann nicht wieder gelesen werden.", "La nouvelle sauvegarde ne peut pas etre relue.", "La nueva partida no se puede volver a leer.";
+    VerifyChecksum: "The checksum of sector {} doesn't match.", "Die Pruefsumme von Sektor {} stimmt nicht.", "La somme de controle du secteur {} ne correspond pas.", "La suma de control del sector {} no coincide.";
+    VerifyMixedIndices: "The sectors of the new save have different save indices.", "Die Sektoren des neuen Spielstands haben verschiedene Speicherzaehler.", "Les secteurs de la nouvelle sauvegarde ont des index differents.", "Los sectores de la nueva partida tienen indices distintos.";
+    VerifyIndexBackwards: "The save index went backwards.", "Der Speicherzaehler ist zurueckgegangen.", "L'index de sauvegarde a recule.", "El indice de guardado ha retrocedido.";
+    VerifyPokemonCount: "The save would have {} Pokemon instead of {}.", "Der Spielstand haette {} Pokemon statt {}.", "La sauvegarde aurait {} Pokemon au lieu de {}.", "La partida tendria {} Pokemon en vez de {}.";
+    VerifyEggChanged: "The received egg lost its hatch steps or its trainer.", "Das erhaltene Ei hat seine Schritte bis zum Schluepfen oder seinen Trainer verloren.", "L'oeuf recu a perdu ses pas avant eclosion ou son dresseur.", "El huevo recibido ha perdido sus pasos para eclosionar o su entrenador.";
+    VerifyPartyChanged: "The party doesn't contain what was traded.", "Das Team enthaelt nicht, was getauscht wurde.", "L'equipe ne contient pas ce qui a ete echange.", "El equipo no contiene lo que se intercambio.";
+    VerifyBoxesChanged: "The Pokemon in the boxes changed.", "Die Pokemon in den Boxen haben sich veraendert.", "Les Pokemon des boites ont change.", "Los Pokemon de las cajas han cambiado.";
+    MailFailed: "Failed to move the mail of save file {}. Don't worry, nothing has been written yet.", "Die Briefe von Spielstand {} konnten nicht uebertragen werden. Keine Sorge, es wurde noch nichts geschrieben.", "Les lettres de la sauvegarde {} n'ont pas pu etre deplacees. Pas d'inquietude, rien n'a encore ete ecrit.", "No se pudieron mover las cartas de la partida {}. Tranquilo, aun no se ha escrito nada.";
+    SavesNotLoaded: "The saves of the trade screen aren't loaded. Open the trade screen again.", "Die Spielstaende des Tauschbildschirms sind nicht geladen. Oeffne ihn erneut.", "Les sauvegardes de l'ecran d'echange ne sont pas chargees. Rouvrez-le.", "Las partidas de la pantalla de intercambio no estan cargadas. Vuelve a abrirla.";
+    InvalidNickname: "'{}' is not a valid nickname.", "'{}' ist kein gueltiger Spitzname.", "'{}' n'est pas un surnom valide.", "'{}' no es un mote valido.";
+    TradeIncomplete: "The trade was only written to save file {}, {} still has the Pokemon it sent.", "Der Tausch wurde nur in Spielstand {} geschrieben, {} hat das gesendete Pokemon noch.", "L'echange n'a ete ecrit que dans la sauvegarde {}, {} a encore le Pokemon envoye.", "El intercambio solo se escribio en la partida {}, {} aun tiene el Pokemon enviado.";
+    ReadSaveFailed: "Failed to read save file {}.", "Spielstand {} konnte nicht gelesen werden.", "La sauvegarde {} n'a pas pu etre lue.", "No se pudo leer la partida {}.";
+    ReadBeforeWriteFailed: "Failed to read save file {}. Nothing has been written yet.", "Spielstand {} konnte nicht gelesen werden. Es wurde noch nichts geschrieben.", "La sauvegarde {} n'a pas pu etre lue. Rien n'a encore ete ecrit.", "No se pudo leer la partida {}. Aun no se ha escrito nada.";
+    BackupFailed: "Failed to back up save file {}. Nothing has been written yet.", "Spielstand {} konnte nicht gesichert werden. Es wurde noch nichts geschrieben.", "La sauvegarde {} n'a pas pu etre copiee. Rien n'a encore ete ecrit.", "No se pudo copiar la partida {}. Aun no se ha escrito nada.";
+    ReadBackupFailed: "Failed to read the backup of save file {}.", "Die Sicherung von Spielstand {} konnte nicht gelesen werden.", "La copie de la sauvegarde {} n'a pas pu etre lue.", "No se pudo leer la copia de la partida {}.";
+    RestoreFailed: "Failed to restore the backup of save file {}.", "Die Sicherung von Spielstand {} konnte nicht wiederhergestellt werden.", "La copie de la sauvegarde {} n'a pas pu etre restauree.", "No se pudo restaurar la copia de la partida {}.";
+}
+
+/// The language detected from the saves, once it is needed.
+static mut DETECTED: Option<Language> = None;
+/// Set while the saves are read to detect the language. Messages built meanwhile, e.g. for
+/// a save that can't be read, are in English instead of detecting the language again.
+static mut DETECTING: bool = false;
+
+/// Returns the language of the user interface: the one chosen in the settings,
+/// otherwise the language of the saves.
+pub fn language() -> Language {
+    // Tests don't depend on the settings and the saves of the machine they run on.
+    if cfg!(test) {
+        return Language::English;
+    }
+    settings().language.unwrap_or_else(detected_language)
+}
+
+/// Returns the language of the saves that would be traded, English if there are none.
+/// It is only detected once per save folder.
+pub fn detected_language() -> Language {
+    // SAFETY: The UI runs on a single thread, and the statics are only copied or assigned,
+    // never borrowed while the saves are read.
+    unsafe {
+        let detected = DETECTED;
+        if let Some(language) = detected {
+            return language;
+        }
+        if DETECTING {
+            return Language::English;
+        }
+        DETECTING = true;
+    }
+    let language = detect_language();
+    // SAFETY: See above.
+    unsafe {
+        DETECTING = false;
+        DETECTED = Some(language);
+    }
+    language
+}
+
+/// Forgets the detected language, so that it is detected again from the saves of a new folder.
+pub fn forget_detected_language() {
+    // SAFETY: The UI runs on a single thread.
+    unsafe {
+        DETECTED = None;
+    }
+}
+
+fn detect_language() -> Language {
+    find_trade_saves()
+        .ok()
+        .and_then(|(save, _)| load_save(&save).ok())
+        .and_then(|loaded| save_language(&loaded.blocks))
+        .unwrap_or(Language::English)
+}
+
+/// Returns the language of a save. The save itself doesn't store it, but every Pokemon
+/// remembers the language of the game it was caught in, so the Pokemon of the player
+/// tell which game this is.
+pub fn save_language(blocks: &SaveBlocks) -> Option<Language> {
+    let trainer_id = read_u32(&blocks.trainer, 0x0A);
+    let party_offset = blocks.version.party_offset();
+    let party_count = (read_u32(&blocks.state, party_offset) as usize).min(PARTY_SIZE);
+    (0..party_count)
+        .map(|i| {
+            let start = party_offset + 4 + i * PARTY_POKEMON_SIZE;
+            &blocks.state[start..start + PARTY_POKEMON_SIZE]
+        })
+        .filter(|data| read_u32(data, 4) == trainer_id)
+        .find_map(|data| Language::from_game(data[18]))
+}
+
+/// Returns a string in the current language.
+pub fn tr(text: Text) -> &'static str {
+    STRINGS[text as usize][language() as usize]
+}
+
+/// Returns a string in the current language with its placeholders replaced by `args`, in order.
+pub fn trf(text: Text, args: &[&str]) -> String {
+    let mut result = String::new();
+    let mut args = args.iter();
+    let mut rest = tr(text);
+    while let Some(i) = rest.find("{}") {
+        result.push_str(&rest[..i]);
+        result.push_str(args.next().copied().unwrap_or(""));
+        rest = &rest[i + 2..];
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,444 @@
+use crate::prelude::*;
+
+/// The names of the items that Pokemon can hold in the languages of the user interface,
+/// by item id. Unused ids have no name.
+/// Key items, TMs and HMs can't be held and are left out.
+const HELD_ITEM_NAMES: [[&str; 4]; 259] = [
+    ["", "", "", ""],
+    ["Master Ball", "Meisterball", "Master Ball", "Master Ball"],
+    ["Ultra Ball", "Hyperball", "Hyper Ball", "Ultra Ball"],
+    ["Great Ball", "Superball", "Super Ball", "Super Ball"],
+    ["Poke Ball", "Pokeball", "Poke Ball", "Poke Ball"],
+    ["Safari Ball", "Safariball", "Safari Ball", "Safari Ball"],
+    ["Net Ball", "Netzball", "Filet Ball", "Malla Ball"],
+    ["Dive Ball", "Tauchball", "Scuba Ball", "Buceo Ball"],
+    ["Nest Ball", "Nestball", "Faiblo Ball", "Nido Ball"],
+    ["Repeat Ball", "Wiederball", "Bis Ball", "Acopio Ball"],
+    ["Timer Ball", "Timerball", "Chrono Ball", "Turno Ball"],
+    ["Luxury Ball", "Luxusball", "Luxe Ball", "Lujo Ball"],
+    ["Premier Ball", "Premierball", "Honor Ball", "Honor Ball"],
+    ["Potion", "Trank", "Potion", "Pocion"],
+    ["Antidote", "Gegengift", "Antidote", "Antidoto"],
+    ["Burn Heal", "Feuerheiler", "Anti-Brule", "Antiquemar"],
+    ["Ice Heal", "Eisheiler", "Antigel", "Antihielo"],
+    ["Awakening", "Aufwecker", "Reveil", "Despertar"],
+    ["Parlyz Heal", "Para-Heiler", "Anti-Para", "Antiparaliz"],
+    ["Full Restore", "Top-Genesung", "Guerison", "Restaurar Todo"],
+    ["Max Potion", "Top-Trank", "Potion Max", "Pocion Maxima"],
+    ["Hyper Potion", "Hypertrank", "Hyper Potion", "Hiperpocion"],
+    ["Super Potion", "Supertrank", "Super Potion", "Superpocion"],
+    ["Full Heal", "Hyperheiler", "Total Soin", "Cura Total"],
+    ["Revive", "Beleber", "Rappel", "Revivir"],
+    ["Max Revive", "Top-Beleber", "Rappel Max", "Revivir Max."],
+    ["Fresh Water", "Tafelwasser", "Eau Fraiche", "Agua Fresca"],
+    ["Soda Pop", "Sprudel", "Soda Cool", "Refresco"],
+    ["Lemonade", "Limonade", "Limonade", "Limonada"],
+    ["Moomoo Milk", "Kuhmuh-Milch", "Lait Meumeu", "Leche Mu-mu"],
+    [
+        "EnergyPowder",
+        "Energiestaub",
+        "Poudrenergie",
+        "Polvo Energia",
+    ],
+    ["Energy Root", "Kraftwurzel", "Racinenergie", "Raiz Energia"],
+    ["Heal Powder", "Heilpuder", "Poudre Soin", "Polvo Curacion"],
+    [
+        "Revival Herb",
+        "Vitalkraut",
+        "Herbe Rappel",
+        "Hierba Revivir",
+    ],
+    ["Ether", "Aether", "Huile", "Eter"],
+    ["Max Ether", "Top-Aether", "Huile Max", "Eter Max."],
+    ["Elixir", "Elixier", "Elixir", "Elixir"],
+    ["Max Elixir", "Top-Elixier", "Max Elixir", "Elixir Max."],
+    ["Lava Cookie", "Lavakeks", "Lava Cookie", "Galleta Lava"],
+    ["Blue Flute", "Blaue Floete", "Flute Bleue", "Flauta Azul"],
+    [
+        "Yellow Flute",
+        "Gelbe Floete",
+        "Flute Jaune",
+        "Flauta Amarilla",
+    ],
+    ["Red Flute", "Rote Floete", "Flute Rouge", "Flauta Roja"],
+    ["Black Flute", "Schw. Floete", "Flute Noire", "Flauta Negra"],
+    [
+        "White Flute",
+        "Weisse Floete",
+        "Flute Blanche",
+        "Flauta Blanca",
+    ],
+    ["Berry Juice", "Beerensaft", "Jus de Baie", "Zumo de Baya"],
+    [
+        "Sacred Ash",
+        "Zauberasche",
+        "Cendresacree",
+        "Ceniza Sagrada",
+    ],
+    ["Shoal Salt", "Kuestensalz", "Sel Trefonds", "Sal Cardumen"],
+    [
+        "Shoal Shell",
+        "Kuestenschale",
+        "Coquil. Trefonds",
+        "Concha Cardumen",
+    ],
+    ["Red Shard", "Purpurstueck", "Tesson Rouge", "Parte Roja"],
+    ["Blue Shard", "Indigostueck", "Tesson Bleu", "Parte Azul"],
+    [
+        "Yellow Shard",
+        "Gelbstueck",
+        "Tesson Jaune",
+        "Parte Amarilla",
+    ],
+    ["Green Shard", "Gruenstueck", "Tesson Vert", "Parte Verde"],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["HP Up", "KP-Plus", "PV Plus", "Mas PS"],
+    ["Protein", "Protein", "Proteine", "Proteina"],
+    ["Iron", "Eisen", "Fer", "Hierro"],
+    ["Carbos", "Carbon", "Carbone", "Carburante"],
+    ["Calcium", "Kalzium", "Calcium", "Calcio"],
+    [
+        "Rare Candy",
+        "Sonderbonbon",
+        "Super Bonbon",
+        "Caramelo Raro",
+    ],
+    ["PP Up", "AP-Plus", "PP Plus", "Mas PP"],
+    ["Zinc", "Zink", "Zinc", "Zinc"],
+    ["PP Max", "AP-Top", "PP Max", "PP Maximos"],
+    ["", "", "", ""],
+    ["Guard Spec.", "Megablock", "Defense Spec.", "Proteccion"],
+    ["Dire Hit", "Angriffplus", "Muscle +", "Directo"],
+    ["X Attack", "X-Angriff", "Attaque +", "Ataque X"],
+    ["X Defend", "X-Abwehr", "Defense +", "Defensa X"],
+    ["X Speed", "X-Tempo", "Vitesse +", "Velocidad X"],
+    ["X Accuracy", "X-Treffer", "Precision +", "Precision X"],
+    ["X Special", "X-Spezial", "Special +", "Especial X"],
+    ["Poke Doll", "Poke-Puppe", "Poke Poupee", "Poke Muneco"],
+    ["Fluffy Tail", "Eneco-Rute", "Queue Skitty", "Cola Skitty"],
+    ["", "", "", ""],
+    [
+        "Super Repel",
+        "Superschutz",
+        "Super Repousse",
+        "Superrepelente",
+    ],
+    ["Max Repel", "Top-Schutz", "Max Repousse", "Repelente Max."],
+    ["Escape Rope", "Fluchtseil", "Corde Sortie", "Cuerda Huida"],
+    ["Repel", "Schutz", "Repousse", "Repelente"],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["Sun Stone", "Sonnenstein", "Pierre Soleil", "Piedra Solar"],
+    ["Moon Stone", "Mondstein", "Pierre Lune", "Piedra Lunar"],
+    ["Fire Stone", "Feuerstein", "Pierre Feu", "Piedra Fuego"],
+    [
+        "Thunderstone",
+        "Donnerstein",
+        "Pierrefoudre",
+        "Piedra Trueno",
+    ],
+    ["Water Stone", "Wasserstein", "Pierre Eau", "Piedra Agua"],
+    ["Leaf Stone", "Blattstein", "Pierre Plante", "Piedra Hoja"],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["TinyMushroom", "Minipilz", "Petit Champi", "Mini Seta"],
+    ["Big Mushroom", "Riesenpilz", "Gros Champi", "Seta Grande"],
+    ["", "", "", ""],
+    ["Pearl", "Perle", "Perle", "Perla"],
+    ["Big Pearl", "Riesenperle", "Grande Perle", "Perla Grande"],
+    [
+        "Stardust",
+        "Sternenstaub",
+        "Poussiere Etoile",
+        "Polvoestelar",
+    ],
+    [
+        "Star Piece",
+        "Sternenstueck",
+        "Morceau Etoile",
+        "Trozo Estrella",
+    ],
+    ["Nugget", "Nugget", "Pepite", "Pepita"],
+    [
+        "Heart Scale",
+        "Herzschuppe",
+        "Ecaille Coeur",
+        "Escama Corazon",
+    ],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    [
+        "Orange Mail",
+        "Orangebrief",
+        "Lettre Orange",
+        "Carta Naranja",
+    ],
+    ["Harbor Mail", "Hafenbrief", "Lettre Port", "Carta Puerto"],
+    [
+        "Glitter Mail",
+        "Glitzerbrief",
+        "Lettre Brille",
+        "Carta Brillo",
+    ],
+    ["Mech Mail", "Eilbrief", "Lettre Meca", "Carta Mecanica"],
+    ["Wood Mail", "Waldbrief", "Lettre Bois", "Carta Madera"],
+    ["Wave Mail", "Wellenbrief", "Lettre Vague", "Carta Ola"],
+    ["Bead Mail", "Perlenbrief", "Lettre Bulle", "Carta Perlas"],
+    ["Shadow Mail", "Dunkelbrief", "Lettre Ombre", "Carta Sombra"],
+    [
+        "Tropic Mail",
+        "Tropenbrief",
+        "Lettre Tropic",
+        "Carta Tropical",
+    ],
+    ["Dream Mail", "Traumbrief", "Lettre Songe", "Carta Sueno"],
+    ["Fab Mail", "Edelbrief", "Lettre Cool", "Carta Fabulosa"],
+    ["Retro Mail", "Retrobrief", "Lettre Retro", "Carta Retro"],
+    ["Cheri Berry", "Amrenabeere", "Baie Ceriz", "Baya Zreza"],
+    ["Chesto Berry", "Maronbeere", "Baie Maron", "Baya Atania"],
+    ["Pecha Berry", "Pirsifbeere", "Baie Pecha", "Baya Meloc"],
+    ["Rawst Berry", "Fragiabeere", "Baie Fraive", "Baya Safre"],
+    ["Aspear Berry", "Wilbirbeere", "Baie Willia", "Baya Perasi"],
+    ["Leppa Berry", "Jonagobeere", "Baie Mepo", "Baya Zanama"],
+    ["Oran Berry", "Sinelbeere", "Baie Oran", "Baya Aranja"],
+    ["Persim Berry", "Persimbeere", "Baie Kika", "Baya Caquic"],
+    ["Lum Berry", "Prunusbeere", "Baie Prine", "Baya Ziuela"],
+    ["Sitrus Berry", "Tsitrubeere", "Baie Sitrus", "Baya Zidra"],
+    ["Figy Berry", "Giefebeere", "Baie Figuy", "Baya Higog"],
+    ["Wiki Berry", "Wikibeere", "Baie Wiki", "Baya Wiki"],
+    ["Mago Berry", "Magobeere", "Baie Mago", "Baya Ango"],
+    ["Aguav Berry", "Gauvebeere", "Baie Gowav", "Baya Guaya"],
+    ["Iapapa Berry", "Yapabeere", "Baie Papaya", "Baya Pabaya"],
+    ["Razz Berry", "Himmihbeere", "Baie Framby", "Baya Frambu"],
+    ["Bluk Berry", "Morbbeere", "Baie Remu", "Baya Oram"],
+    ["Nanab Berry", "Nanabbeere", "Baie Nanab", "Baya Latano"],
+    ["Wepear Berry", "Nirbebeere", "Baie Repoi", "Baya Peragu"],
+    ["Pinap Berry", "Sananabeere", "Baie Nanana", "Baya Pinia"],
+    ["Pomeg Berry", "Granabeere", "Baie Grena", "Baya Grana"],
+    ["Kelpsy Berry", "Setangbeere", "Baie Alga", "Baya Algama"],
+    ["Qualot Berry", "Qualotbeere", "Baie Qualot", "Baya Ispero"],
+    ["Hondew Berry", "Honmelbeere", "Baie Lonme", "Baya Meluce"],
+    ["Grepa Berry", "Labrusbeere", "Baie Resin", "Baya Uvav"],
+    ["Tamato Berry", "Tamotbeere", "Baie Tamato", "Baya Tamate"],
+    ["Cornn Berry", "Saimbeere", "Baie Siam", "Baya Mais"],
+    ["Magost Berry", "Magostbeere", "Baie Mangou", "Baya Aostan"],
+    ["Rabuta Berry", "Rabutabeere", "Baie Rabuta", "Baya Rautan"],
+    ["Nomel Berry", "Tronzibeere", "Baie Tronci", "Baya Monli"],
+    ["Spelon Berry", "Kiwanbeere", "Baie Kiwan", "Baya Wikano"],
+    ["Pamtre Berry", "Pallmbeere", "Baie Palma", "Baya Plama"],
+    ["Watmel Berry", "Wasmelbeere", "Baie Stekpa", "Baya Sambia"],
+    ["Durin Berry", "Durinbeere", "Baie Durin", "Baya Rudion"],
+    ["Belue Berry", "Myrtilbeere", "Baie Myrte", "Baya Andano"],
+    ["Liechi Berry", "Lydzibeere", "Baie Lichii", "Baya Lichi"],
+    ["Ganlon Berry", "Linganbeere", "Baie Lingan", "Baya Gonlan"],
+    ["Salac Berry", "Salkabeere", "Baie Sailak", "Baya Aslac"],
+    ["Petaya Berry", "Tahaybeere", "Baie Pitaye", "Baya Yapati"],
+    ["Apicot Berry", "Apikobeere", "Baie Abriko", "Baya Aricoc"],
+    ["Lansat Berry", "Lansatbeere", "Baie Lansat", "Baya Zonlan"],
+    ["Starf Berry", "Krambobeere", "Baie Frista", "Baya Arabol"],
+    ["Enigma Berry", "Enigmabeere", "Baie Enigma", "Baya Enigma"],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    [
+        "BrightPowder",
+        "Blendpuder",
+        "Poudre Claire",
+        "Polvo Brillo",
+    ],
+    [
+        "White Herb",
+        "Schlohkraut",
+        "Herbe Blanche",
+        "Hierba Blanca",
+    ],
+    ["Macho Brace", "Machoband", "Bracelet Macho", "Brazal Firme"],
+    ["Exp. Share", "EP-Teiler", "Multi Exp", "Repartir Exp"],
+    ["Quick Claw", "Flinkklaue", "Vive Griffe", "Garra Rapida"],
+    ["Soothe Bell", "Sanftglocke", "Grelot Zen", "Campana Alivio"],
+    [
+        "Mental Herb",
+        "Mentalkraut",
+        "Herbe Mental",
+        "Hierba Mental",
+    ],
+    ["Choice Band", "Wahlband", "Bandeau Choix", "Cinta Eleccion"],
+    ["King's Rock", "King-Stein", "Roche Royale", "Roca del Rey"],
+    [
+        "SilverPowder",
+        "Silberstaub",
+        "Poudre Argentee",
+        "Polvo Plata",
+    ],
+    [
+        "Amulet Coin",
+        "Muenzamulett",
+        "Piece Rune",
+        "Moneda Amuleto",
+    ],
+    ["Cleanse Tag", "Schutzband", "Rune Purif", "Amuleto"],
+    ["Soul Dew", "Seelentau", "Rosee Ame", "Rocio Bondad"],
+    ["DeepSeaTooth", "Abysszahn", "Dent Ocean", "Diente Marino"],
+    [
+        "DeepSeaScale",
+        "Abyssplatte",
+        "Ecaille Ocean",
+        "Escama Marina",
+    ],
+    ["Smoke Ball", "Rauchball", "Boule Fumee", "Bola Humo"],
+    ["Everstone", "Ewigstein", "Pierre Stase", "Piedra Eterna"],
+    ["Focus Band", "Fokus-Band", "Bandeau", "Cinta Focus"],
+    ["Lucky Egg", "Gluecks-Ei", "Oeuf Chance", "Huevo Suerte"],
+    ["Scope Lens", "Scope-Linse", "Lentilscope", "Periscopio"],
+    [
+        "Metal Coat",
+        "Metallmantel",
+        "Peau Metal",
+        "Revest. Metalico",
+    ],
+    ["Leftovers", "Ueberreste", "Restes", "Restos"],
+    [
+        "Dragon Scale",
+        "Drachenhaut",
+        "Ecaille Draco",
+        "Escama Dragon",
+    ],
+    ["Light Ball", "Kugelblitz", "Balle Lumiere", "Bola Luminosa"],
+    ["Soft Sand", "Pudersand", "Sable Doux", "Arena Fina"],
+    ["Hard Stone", "Granitstein", "Pierre Dure", "Piedra Dura"],
+    [
+        "Miracle Seed",
+        "Wundersaat",
+        "Grain Miracle",
+        "Semilla Milagro",
+    ],
+    [
+        "BlackGlasses",
+        "Schattenglas",
+        "Lunettes Noires",
+        "Gafas de Sol",
+    ],
+    [
+        "Black Belt",
+        "Schwarzgurt",
+        "Ceinture Noire",
+        "Cinturon Negro",
+    ],
+    ["Magnet", "Magnet", "Aimant", "Iman"],
+    [
+        "Mystic Water",
+        "Zauberwasser",
+        "Eau Mystique",
+        "Agua Mistica",
+    ],
+    ["Sharp Beak", "Hackattack", "Bec Pointu", "Pico Afilado"],
+    ["Poison Barb", "Giftstich", "Pic Venin", "Flecha Venenosa"],
+    [
+        "NeverMeltIce",
+        "Ewiges Eis",
+        "Glace Eternelle",
+        "Antiderretir",
+    ],
+    ["Spell Tag", "Bannsticker", "Rune Sort", "Hechizo"],
+    [
+        "TwistedSpoon",
+        "Kruemmloeffel",
+        "Cuillere Tordue",
+        "Cuchara Torcida",
+    ],
+    ["Charcoal", "Holzkohle", "Charbon", "Carbon"],
+    [
+        "Dragon Fang",
+        "Drachenzahn",
+        "Croc Dragon",
+        "Colmillo Dragon",
+    ],
+    ["Silk Scarf", "Seidenschal", "Mouchoir Soie", "Panuelo Seda"],
+    ["Up-Grade", "Up-Grade", "Ameliorator", "Mejora"],
+    ["Shell Bell", "Seegesang", "Grelot Coque", "Campana Concha"],
+    ["Sea Incense", "Seerauch", "Encens Mer", "Incienso Marino"],
+    ["Lax Incense", "Laxrauch", "Encens Doux", "Incienso Suave"],
+    ["Lucky Punch", "Lucky Punch", "Poing Chance", "Puno Suerte"],
+    [
+        "Metal Powder",
+        "Metallstaub",
+        "Poudre Metal",
+        "Polvo Metalico",
+    ],
+    ["Thick Club", "Kampfknochen", "Masse Os", "Hueso Grueso"],
+    ["Stick", "Lauchstange", "Baton", "Palo"],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["", "", "", ""],
+    ["Red Scarf", "Roter Schal", "Foulard Rouge", "Panuelo Rojo"],
+    ["Blue Scarf", "Blauer Schal", "Foulard Bleu", "Panuelo Azul"],
+    ["Pink Scarf", "Rosa Schal", "Foulard Rose", "Panuelo Rosa"],
+    [
+        "Green Scarf",
+        "Gruener Schal",
+        "Foulard Vert",
+        "Panuelo Verde",
+    ],
+    [
+        "Yellow Scarf",
+        "Gelber Schal",
+        "Foulard Jaune",
+        "Panuelo Amarillo",
+    ],
+];
+
+/// Returns the name of the item with the given id, if a Pokemon can hold it.
+pub fn item_name(id: u16) -> Option<&'static str> {
+    HELD_ITEM_NAMES
+        .get(id as usize)
+        .map(|names| names[language() as usize])
+        .filter(|name| !name.is_empty())
+}
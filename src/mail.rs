@@ -0,0 +1,198 @@
+use crate::prelude::*;
+
+/// The size of a mail: the 9 words of the message (18 bytes), the author (8), their
+/// trainer id (4), the species of the Pokemon (2) and the mail item (2).
+const MAIL_SIZE: usize = 36;
+/// Offset of the mail item in a mail. Zero marks a free mail slot.
+const OFFSET_MAIL_ITEM: usize = 32;
+/// The number of mail slots that belong to the party. The rest is the PC mailbox.
+const PARTY_MAIL_SLOTS: usize = 6;
+/// The number of mail slots in the game state.
+const MAIL_SLOTS: usize = 16;
+/// Offset of the mail slot in the stats of a party Pokemon.
+const OFFSET_MAIL_ID: usize = 85;
+/// The mail slot of a Pokemon that holds no mail.
+const NO_MAIL: u8 = 0xFF;
+
+/// Moves the mail held by the traded Pokemon along with them.
+///
+/// The mail itself isn't part of a Pokemon, the game keeps it in a separate list and
+/// the Pokemon only remembers its slot. After a trade both saves still contain the mail
+/// they had before, and the received Pokemon, appended to the end of the party, points
+/// at a slot in the save it came from. The mail of the other save is copied into a free
+/// party slot and the slot of the Pokemon that left is cleared.
+pub fn move_mail(buf1: &mut [u8], buf2: &mut [u8], names: (&str, &str)) -> Result<()> {
+    let blocks1 = mail_blocks(buf1, names.0)?;
+    let blocks2 = mail_blocks(buf2, names.1)?;
+    let received1 = received_mail(&blocks1, &blocks2);
+    let received2 = received_mail(&blocks2, &blocks1);
+
+    // Both buffers are only written once every mail has been read.
+    place_mail(buf1, &blocks1, received1, mail_id(&blocks2), names.0)?;
+    place_mail(buf2, &blocks2, received2, mail_id(&blocks1), names.1)?;
+    Ok(())
+}
+
+fn mail_blocks(buf: &[u8], name: &str) -> Result<SaveBlocks> {
+    parse_save_blocks(buf).ok_or_else(|| AppError::MailFailed(name.to_string()))
+}
+
+/// Returns the state offset of the last party Pokemon, which is the one received in the trade.
+fn received_offset(blocks: &SaveBlocks) -> usize {
+    let party_offset = blocks.version.party_offset();
+    let party_count = (read_u32(&blocks.state, party_offset) as usize).clamp(1, PARTY_SIZE);
+    party_offset + 4 + (party_count - 1) * PARTY_POKEMON_SIZE
+}
+
+/// Returns the mail slot that the received Pokemon had in the save it came from.
+fn mail_id(blocks: &SaveBlocks) -> Option<usize> {
+    let id = blocks.state[received_offset(blocks) + OFFSET_MAIL_ID] as usize;
+    (id < MAIL_SLOTS).then_some(id)
+}
+
+/// Returns the mail that the Pokemon received by `blocks` holds, taken from the save it came from.
+fn received_mail(blocks: &SaveBlocks, from: &SaveBlocks) -> Option<Vec<u8>> {
+    let start = from.version.mail_offset() + mail_id(blocks)? * MAIL_SIZE;
+    Some(from.state[start..start + MAIL_SIZE].to_vec())
+}
+
+/// Clears the mail of the Pokemon that left and stores the mail of the received one.
+fn place_mail(
+    buf: &mut [u8],
+    blocks: &SaveBlocks,
+    mail: Option<Vec<u8>>,
+    departed: Option<usize>,
+    name: &str,
+) -> Result<()> {
+    let mail_offset = blocks.version.mail_offset();
+    let write_error = || AppError::MailFailed(name.to_string());
+
+    if let Some(slot) = departed {
+        write_state(buf, mail_offset + slot * MAIL_SIZE, &[0; MAIL_SIZE])
+            .ok_or_else(write_error)?;
+    }
+
+    let mail_id = match mail {
+        Some(mail) => {
+            let slot = (0..PARTY_MAIL_SLOTS)
+                .find(|&slot| Some(slot) == departed || is_free_slot(blocks, slot))
+                .ok_or_else(|| AppError::NoMailRoom(name.to_string()))?;
+            write_state(buf, mail_offset + slot * MAIL_SIZE, &mail).ok_or_else(write_error)?;
+            slot as u8
+        }
+        None => NO_MAIL,
+    };
+    write_state(buf, received_offset(blocks) + OFFSET_MAIL_ID, &[mail_id]).ok_or_else(write_error)
+}
+
+/// Returns true if the mail slot holds no mail and no Pokemon that stays in the party
+/// points at it. The received Pokemon is left out, its slot belongs to the other save.
+fn is_free_slot(blocks: &SaveBlocks, slot: usize) -> bool {
+    let item = blocks.version.mail_offset() + slot * MAIL_SIZE + OFFSET_MAIL_ITEM;
+    let party_offset = blocks.version.party_offset();
+    let received = received_offset(blocks);
+    u16::from_le_bytes([blocks.state[item], blocks.state[item + 1]]) == 0
+        && (party_offset + 4..received)
+            .step_by(PARTY_POKEMON_SIZE)
+            .all(|pokemon| blocks.state[pokemon + OFFSET_MAIL_ID] as usize != slot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save_data::tests::{add_to_party, blank_blocks, pokemon, save_file};
+
+    /// The item id of the first kind of mail.
+    const ORANGE_MAIL: u16 = 121;
+
+    /// Returns a mail with the given item and a message made of the item id.
+    fn mail(item: u16) -> Vec<u8> {
+        let mut mail = vec![item as u8; MAIL_SIZE];
+        mail[OFFSET_MAIL_ITEM..].copy_from_slice(&item.to_le_bytes());
+        mail
+    }
+
+    /// Returns blocks whose party holds the given mail slots, `NO_MAIL` for none.
+    fn party_with_mail(mail_ids: &[u8]) -> SaveBlocks {
+        let mut blocks = blank_blocks(GameVersion::Emerald);
+        for (i, &id) in mail_ids.iter().enumerate() {
+            add_to_party(&mut blocks, &pokemon(25), 10);
+            let start = blocks.version.party_offset() + 4 + i * PARTY_POKEMON_SIZE;
+            blocks.state[start + OFFSET_MAIL_ID] = id;
+        }
+        blocks
+    }
+
+    fn set_mail(blocks: &mut SaveBlocks, slot: usize, mail: &[u8]) {
+        let start = blocks.version.mail_offset() + slot * MAIL_SIZE;
+        blocks.state[start..start + MAIL_SIZE].copy_from_slice(mail);
+    }
+
+    fn mail_at(blocks: &SaveBlocks, slot: usize) -> &[u8] {
+        let start = blocks.version.mail_offset() + slot * MAIL_SIZE;
+        &blocks.state[start..start + MAIL_SIZE]
+    }
+
+    #[test]
+    fn keeps_the_item_at_the_end_of_a_mail() {
+        // Words (18), author (8), trainer id (4) and species (2) come first.
+        assert_eq!(OFFSET_MAIL_ITEM, 18 + 8 + 4 + 2);
+        assert_eq!(OFFSET_MAIL_ITEM + 2, MAIL_SIZE);
+    }
+
+    #[test]
+    fn moves_the_mail_with_the_traded_pokemon() {
+        // The first save kept the Pokemon with the mail in slot 0 and received one whose
+        // mail is in slot 0 of the other save. The one it sent had the mail in slot 1.
+        let mut blocks1 = party_with_mail(&[0, 0]);
+        set_mail(&mut blocks1, 0, &mail(ORANGE_MAIL));
+        set_mail(&mut blocks1, 1, &mail(ORANGE_MAIL + 1));
+        let mut blocks2 = party_with_mail(&[NO_MAIL, 1]);
+        set_mail(&mut blocks2, 0, &mail(ORANGE_MAIL + 2));
+        let mut buf1 = save_file(&blocks1, 1);
+        let mut buf2 = save_file(&blocks2, 1);
+
+        move_mail(&mut buf1, &mut buf2, ("ruby", "emerald")).unwrap();
+
+        let after1 = parse_save_blocks(&buf1).unwrap();
+        let after2 = parse_save_blocks(&buf2).unwrap();
+        assert!(invalid_sectors(&buf1).is_empty() && invalid_sectors(&buf2).is_empty());
+        // The mail that stays isn't overwritten, the received one takes the free slot.
+        assert_eq!(mail_at(&after1, 0), mail(ORANGE_MAIL));
+        assert_eq!(mail_at(&after1, 1), mail(ORANGE_MAIL + 2));
+        assert_eq!(mail_id(&after1), Some(1));
+        assert_eq!(mail_at(&after2, 0), mail(ORANGE_MAIL + 1));
+        assert_eq!(mail_id(&after2), Some(0));
+    }
+
+    #[test]
+    fn clears_the_mail_of_the_pokemon_that_left() {
+        let mut blocks1 = party_with_mail(&[NO_MAIL, NO_MAIL]);
+        let blocks2 = party_with_mail(&[NO_MAIL, 3]);
+        set_mail(&mut blocks1, 3, &mail(ORANGE_MAIL));
+        let mut buf1 = save_file(&blocks1, 1);
+        let mut buf2 = save_file(&blocks2, 1);
+
+        move_mail(&mut buf1, &mut buf2, ("ruby", "emerald")).unwrap();
+
+        let after1 = parse_save_blocks(&buf1).unwrap();
+        assert_eq!(mail_at(&after1, 3), [0; MAIL_SIZE]);
+        assert_eq!(mail_id(&after1), None);
+    }
+
+    #[test]
+    fn skips_slots_that_a_pokemon_points_at() {
+        // Slot 0 has no item but still belongs to the first Pokemon.
+        let mut blocks = party_with_mail(&[0, 0]);
+        for slot in 1..PARTY_MAIL_SLOTS {
+            set_mail(&mut blocks, slot, &mail(ORANGE_MAIL));
+        }
+        assert!(!is_free_slot(&blocks, 0));
+        let mut buf = save_file(&blocks, 1);
+        let result = place_mail(&mut buf, &blocks, Some(mail(ORANGE_MAIL)), None, "ruby");
+        assert!(matches!(result, Err(AppError::NoMailRoom(_))));
+
+        let blocks = party_with_mail(&[NO_MAIL, 0]);
+        assert!(is_free_slot(&blocks, 0));
+    }
+}
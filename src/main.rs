@@ -11,23 +11,39 @@ extern crate ndless_handler;
 mod assets;
 mod bindings;
 mod errors;
+mod i18n;
+mod items;
+mod mail;
 mod navigator;
 mod platform;
+mod pokedex;
 mod prelude;
+mod save_data;
+#[cfg(not(feature = "calculator-build"))]
+mod save_diff;
 mod saves;
+mod search;
+mod settings;
+mod species;
+mod trainer_card;
 mod ui;
+mod verify;
 
 use crate::prelude::*;
 
 fn main() {
+    // Read the settings first, on desktop this handles `--help` before the terminal is set up.
+    init_settings();
+    // Read the saves for the language here, not whenever the first text happens to be shown.
+    language();
     console::init_console();
 
     // Create the navigator with the splash screen as the root screen.
-    let mut navigator = Navigator::new(Box::new(SplashScreen));
+    let mut navigator = Navigator::new(Box::new(SplashScreen::new()));
     navigator.run();
 
     // Before closing, show the exit screen.
-    let mut navigator = Navigator::new(Box::new(ExitScreen));
+    let mut navigator = Navigator::new(Box::new(ExitScreen::new()));
     navigator.run();
 
     console::dispose();
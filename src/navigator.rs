@@ -1,18 +1,53 @@
 use crate::prelude::*;
+use core::any::Any;
+
+/// A value that a popped screen hands back to the screen below it.
+/// The receiving screen downcasts it to the type it expects.
+pub type ScreenResult = Box<dyn Any>;
 
 pub enum NavAction {
     Push(Box<dyn Screen>),
     Pop,
+    /// Pops the current screen and passes the result to `Screen::on_result` of the screen below.
+    PopWith(ScreenResult),
     Exit,
     Go(Box<dyn Screen>),
     None,
 }
 
+impl NavAction {
+    /// Pops the current screen with a typed result.
+    pub fn pop_with<T: Any>(value: T) -> Self {
+        NavAction::PopWith(Box::new(value))
+    }
+}
+
+/// How long the UI loop waits for input before the next frame.
+#[cfg(feature = "calculator-build")]
+// Frame times on a calculator are nowhere near 60 FPS.
+const FRAME_TIME: u32 = 1000 / 30;
+
+#[cfg(feature = "desktop")]
+const FRAME_TIME: u32 = 1000 / 60;
+
 /// The Screen trait defines the interface for all UI screens.
 pub trait Screen {
     fn init(&mut self) -> Result<NavAction>;
     fn build(&mut self);
-    fn handle_input(&mut self) -> Result<NavAction>;
+    /// Handles a single key press. Called at most once per frame.
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction>;
+
+    /// Advances animations and timers by `dt` milliseconds. Called once per frame,
+    /// before any input is handled.
+    fn tick(&mut self, _dt: u32) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
+
+    /// Receives the result of a screen that was pushed by this screen and popped with
+    /// `NavAction::PopWith`. Results of unexpected types should be ignored.
+    fn on_result(&mut self, _result: ScreenResult) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
 }
 
 /// The Navigator struct maintains a stack of screens and handles transitions.
@@ -31,25 +66,38 @@ impl Navigator {
                 nav.process_action(action);
             }
             Err(e) => {
-                nav.stack.push(Box::new(ErrorScreen { error: e }));
+                nav.stack.push(Box::new(ErrorScreen::new(e).retry(root)));
             }
         }
         nav
     }
 
     /// Runs the UI loop until there are no more screens.
+    ///
+    /// Every frame, the top screen is built, ticked and then handed the key that was
+    /// pressed while waiting for the next frame (if any).
     pub fn run(&mut self) {
+        let mut last_frame = now_ms();
         while let Some(screen) = self.stack.last_mut() {
-            console::clear_screen();
+            // Screens present their frame themselves, the console only redraws what changed.
             screen.build();
-            console::flush();
 
-            match screen.handle_input() {
+            let input = poll_input(FRAME_TIME);
+            let now = now_ms();
+            let dt = now.wrapping_sub(last_frame);
+            last_frame = now;
+
+            let result = screen.tick(dt).and_then(|action| match (action, input) {
+                (NavAction::None, Some(input)) => screen.handle_input(input),
+                (action, _) => Ok(action),
+            });
+
+            match result {
                 Ok(action) => {
                     self.process_action(action);
                 }
                 Err(e) => {
-                    self.stack.push(Box::new(ErrorScreen { error: e }));
+                    self.stack.push(Box::new(ErrorScreen::new(e)));
                 }
             }
         }
@@ -59,7 +107,7 @@ impl Navigator {
         match action {
             NavAction::Push(mut screen) => {
                 if let Err(e) = screen.init() {
-                    self.stack.push(Box::new(ErrorScreen { error: e }));
+                    self.stack.push(Box::new(ErrorScreen::new(e).retry(screen)));
                 } else {
                     self.stack.push(screen);
                 }
@@ -67,14 +115,25 @@ impl Navigator {
             NavAction::Pop => {
                 self.stack.pop();
             }
+            NavAction::PopWith(result) => {
+                self.stack.pop();
+                if let Some(parent) = self.stack.last_mut() {
+                    match parent.on_result(result) {
+                        Ok(action) => self.process_action(action),
+                        Err(e) => self.stack.push(Box::new(ErrorScreen::new(e))),
+                    }
+                }
+            }
             NavAction::Exit => {
                 self.stack.clear();
             }
             NavAction::Go(mut screen) => {
+                // The current screen is replaced either way, so retrying from an error
+                // screen doesn't pile up error screens.
+                self.stack.pop();
                 if let Err(e) = screen.init() {
-                    self.stack.push(Box::new(ErrorScreen { error: e }));
+                    self.stack.push(Box::new(ErrorScreen::new(e).retry(screen)));
                 } else {
-                    self.stack.pop();
                     self.stack.push(screen);
                 }
             }
@@ -1,7 +1,111 @@
+#[cfg(feature = "calculator-build")]
+use crate::prelude::*;
+
+/// The frame that is currently visible on screen.
+///
+/// `present` compares every new frame against it and only emits the cells that changed,
+/// which avoids the flicker of clearing and reprinting the whole screen on every frame.
+struct FrameCache {
+    /// The characters on screen, one vector per row.
+    rows: Vec<Vec<char>>,
+    /// The foreground color the cached frame was drawn with.
+    color: Option<u8>,
+    /// If set, the next `present` repaints every cell.
+    full_repaint: bool,
+}
+
+static mut FRAME_CACHE: FrameCache = FrameCache {
+    rows: Vec::new(),
+    color: None,
+    full_repaint: true,
+};
+
+#[allow(static_mut_refs)]
+fn frame_cache() -> &'static mut FrameCache {
+    // SAFETY: The UI runs on a single thread and never holds on to the reference.
+    unsafe { &mut FRAME_CACHE }
+}
+
+/// Forces the next call to `present` to repaint the whole screen.
+pub fn invalidate() {
+    frame_cache().full_repaint = true;
+}
+
+/// Sets the text color.
+///
+/// Changing the color invalidates the frame cache, because every cell on screen
+/// has to be redrawn in the new color.
+pub fn set_color(foreground: u8) {
+    let cache = frame_cache();
+    if cache.color != Some(foreground) {
+        cache.color = Some(foreground);
+        cache.full_repaint = true;
+    }
+    set_foreground(foreground);
+}
+
+/// Presents a rendered frame (rows separated by newlines) and flushes the console.
+///
+/// Only the runs of cells that differ from the previously presented frame are written,
+/// using cursor positioning. A full repaint happens on the first frame, after `invalidate`
+/// and whenever the frame dimensions change.
+pub fn present(frame: &str) {
+    let cache = frame_cache();
+
+    let same_shape = cache.rows.len() == frame.split('\n').count()
+        && cache
+            .rows
+            .iter()
+            .zip(frame.split('\n'))
+            .all(|(old, new)| old.len() == new.chars().count());
+
+    if cache.full_repaint || !same_shape {
+        clear_screen();
+        print(frame);
+
+        // Reuse the row buffers where possible, frames rarely change their shape.
+        cache.rows.resize_with(frame.split('\n').count(), Vec::new);
+        for (row, line) in cache.rows.iter_mut().zip(frame.split('\n')) {
+            row.clear();
+            row.extend(line.chars());
+        }
+    } else {
+        let mut run = String::new();
+        for (y, (old, new)) in cache.rows.iter_mut().zip(frame.split('\n')).enumerate() {
+            let mut start = None;
+            for (x, ch) in new.chars().chain(core::iter::once('\n')).enumerate() {
+                let changed = x < old.len() && old[x] != ch;
+                if changed {
+                    // Collect the whole run of changed cells so it can be written in one go.
+                    start.get_or_insert(x);
+                    run.push(ch);
+                    old[x] = ch;
+                } else if let Some(s) = start.take() {
+                    print_at(s as u16, y as u16, &run);
+                    run.clear();
+                }
+            }
+        }
+    }
+
+    cache.full_repaint = false;
+    flush();
+}
+
 #[cfg(feature = "calculator-build")]
 mod calculator {
     use crate::bindings::nspireio::*;
+    use crate::ui::rendering::{NIO_MAX_COLS, NIO_MAX_ROWS};
     use ndless::ffi::CString;
+    use ndless_sys::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+    /// The number of fully visible cells (columns, rows), determined in `init_console`.
+    static mut SIZE: (u16, u16) = (NIO_MAX_COLS, NIO_MAX_ROWS);
+
+    /// Returns the size of the console in cells (columns, rows).
+    pub fn size() -> (u16, u16) {
+        unsafe { SIZE }
+    }
 
     /// Prints a string to the calculator's screen.
     pub fn print(s: &str) {
@@ -10,13 +114,33 @@ mod calculator {
         }
     }
 
+    /// Prints a string to the calculator's screen, starting at the given cell.
+    ///
+    /// nspireio doesn't understand cursor escape sequences, so the characters are stored
+    /// directly into the console buffer and drawn on the next flush.
+    pub fn print_at(x: u16, y: u16, s: &str) {
+        let csl = unsafe { nio_get_default() };
+        for (i, ch) in s.chars().enumerate() {
+            let ch = if ch.is_ascii() { ch as u8 } else { b'?' };
+            unsafe {
+                nio_csl_savechar(
+                    csl,
+                    ch as core::ffi::c_char,
+                    (x as usize + i) as i32,
+                    y as i32,
+                )
+            };
+        }
+    }
+
     /// Clears the calculator's screen.
     pub fn clear_screen() {
+        super::invalidate();
         unsafe { nio_clear(nio_get_default()) }
     }
 
-    /// Sets the text color on the calculator.
-    pub fn set_color(foreground: u8) {
+    /// Sets the text color on the calculator. Use `console::set_color` instead.
+    pub fn set_foreground(foreground: u8) {
         unsafe { nio_color(nio_get_default(), NIO_COLOR_BLACK as u8, foreground) }
     }
 
@@ -27,10 +151,17 @@ mod calculator {
             CONSOLE = Some(core::mem::zeroed());
             #[allow(static_mut_refs)]
             let csl = CONSOLE.as_mut().unwrap();
+
+            // The last column is only half visible, so it's part of the console
+            // but isn't used for rendering.
+            let cols = (SCREEN_WIDTH / NIO_CHAR_WIDTH) as u16;
+            let rows = (SCREEN_HEIGHT / NIO_CHAR_HEIGHT) as u16;
+            SIZE = (cols, rows);
+
             nio_init(
                 csl,
-                54,
-                30,
+                cols as i32 + 1,
+                rows as i32,
                 0,
                 0,
                 NIO_COLOR_BLACK as u8,
@@ -58,57 +189,165 @@ mod calculator {
 }
 #[cfg(not(feature = "calculator-build"))]
 mod desktop {
+    use crate::errors::AppError;
     use crate::ui::rendering::{NIO_MAX_COLS, NIO_MAX_ROWS};
     use crossterm::{
-        cursor::MoveTo,
+        cursor::{Hide, MoveTo, MoveToNextLine, Show},
+        event::{DisableMouseCapture, EnableMouseCapture},
         execute, queue,
-        style::{Color, Print, SetForegroundColor},
-        terminal::{Clear, ClearType, SetSize},
+        style::{Color, Print, ResetColor, SetForegroundColor},
+        terminal::{
+            self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+            LeaveAlternateScreen,
+        },
     };
     use std::io::{stdout, Write};
+    use std::path::PathBuf;
+
+    /// Exit code used when the user interrupts the program (128 + SIGINT).
+    const EXIT_INTERRUPTED: i32 = 130;
 
     /// Prints a string to the desktop terminal.
+    ///
+    /// The terminal is in raw mode, so newlines don't return the cursor to the first column.
+    /// Lines are therefore printed one after another with explicit cursor movement.
     pub fn print(s: &str) {
         let mut stdout = stdout();
-        queue!(stdout, Print(s)).unwrap();
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                queue!(stdout, MoveToNextLine(1)).unwrap_or_else(terminal_error);
+            }
+            queue!(stdout, Print(line)).unwrap_or_else(terminal_error);
+        }
+    }
+
+    /// Prints a string to the desktop terminal, starting at the given cell.
+    pub fn print_at(x: u16, y: u16, s: &str) {
+        let mut stdout = stdout();
+        queue!(stdout, MoveTo(x, y), Print(s)).unwrap_or_else(terminal_error);
     }
 
     /// Clears the terminal screen using crossterm commands.
     pub fn clear_screen() {
+        super::invalidate();
         let mut stdout = stdout();
         // Clear the entire screen and move the cursor to the top-left corner.
-        queue!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
-
-        // Purge clears the history.
-        // NOTE: It MUST come after ClearType::All, otherwise the history will be cleared,
-        // then the screen will be cleared which adds the current screen to the history.
-        // -> clear first, then erase history
-        queue!(stdout, Clear(ClearType::Purge), MoveTo(0, 0)).unwrap();
+        queue!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap_or_else(terminal_error);
     }
 
     /// Sets the terminal text color based on the provided color code.
+    /// Use `console::set_color` instead.
     ///
     /// This uses the ANSI value directly via crossterm's `Color::AnsiValue`.
-    pub fn set_color(foreground: u8) {
+    pub fn set_foreground(foreground: u8) {
         let mut stdout = stdout();
-        queue!(stdout, SetForegroundColor(Color::AnsiValue(foreground))).unwrap();
+        queue!(stdout, SetForegroundColor(Color::AnsiValue(foreground)))
+            .unwrap_or_else(terminal_error);
+    }
+
+    /// Returns the size of the terminal in cells (columns, rows).
+    /// Falls back to the calculator's dimensions if the size can't be determined.
+    pub fn size() -> (u16, u16) {
+        terminal::size().unwrap_or((NIO_MAX_COLS, NIO_MAX_ROWS))
     }
 
     /// Initializes the console on the desktop.
+    ///
+    /// Starts a terminal session: the ui is drawn on the alternate screen (so the user's
+    /// scrollback stays untouched), the cursor is hidden and raw mode stays enabled until
+    /// `dispose`. Mouse clicks are reported as input. Panics and termination signals
+    /// restore the terminal before exiting.
     pub fn init_console() {
-        let mut stdout = stdout();
-        // If possible, set the terminal size to the appropriate dimensions.
-        execute!(stdout, SetSize(NIO_MAX_COLS, NIO_MAX_ROWS)).unwrap();
+        install_panic_hook();
+        ctrlc::set_handler(|| interrupt()).expect("Failed to install the signal handler");
+
+        enable_raw_mode().expect("Failed to enable raw mode");
+        execute!(stdout(), EnterAlternateScreen, Hide, EnableMouseCapture)
+            .expect("Failed to set up the terminal");
     }
 
     /// Flushes the terminal output.
     pub fn flush() {
         let mut stdout = stdout();
-        stdout.flush().unwrap();
+        stdout.flush().unwrap_or_else(terminal_error);
     }
 
-    /// Disposes of the console (no-op for desktop).
-    pub fn dispose() {}
+    /// Ends the terminal session and restores the terminal to its original state.
+    pub fn dispose() {
+        restore_terminal();
+    }
+
+    /// Restores the terminal. Errors are ignored, as this also runs while crashing.
+    fn restore_terminal() {
+        let _ = execute!(
+            stdout(),
+            DisableMouseCapture,
+            ResetColor,
+            Show,
+            LeaveAlternateScreen
+        );
+        let _ = disable_raw_mode();
+    }
+
+    /// Ends the session after the user pressed Ctrl+C or the process was asked to terminate.
+    ///
+    /// In raw mode, Ctrl+C doesn't raise a signal but arrives as a key event,
+    /// so the input backend calls this as well.
+    pub fn interrupt() -> ! {
+        restore_terminal();
+        std::process::exit(EXIT_INTERRUPTED);
+    }
+
+    /// Ends the session because of an error that the ui can't recover from.
+    ///
+    /// The terminal is restored first, so the message ends up in the user's scrollback.
+    pub fn fatal(error: AppError) -> ! {
+        restore_terminal();
+        eprintln!("nTrade Advance stopped because of an error: {}", error);
+        report_crash(&error.to_string());
+        std::process::exit(1);
+    }
+
+    /// Fails the session if writing to the terminal fails.
+    fn terminal_error(e: std::io::Error) {
+        fatal(AppError::Terminal(e.to_string()));
+    }
+
+    /// Restores the terminal and writes a crash report before the default hook prints
+    /// the panic message.
+    fn install_panic_hook() {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore_terminal();
+            default_hook(info);
+            report_crash(&info.to_string());
+        }));
+    }
+
+    /// Returns the path of the crash report.
+    fn crash_report_path() -> PathBuf {
+        std::env::temp_dir().join("ntradeadvance-crash.txt")
+    }
+
+    /// Writes a crash report and tells the user where to find it.
+    fn report_crash(message: &str) {
+        let report = format!(
+            "nTrade Advance v{} ({} {})\n\n{}\n\nBacktrace:\n{}\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            message,
+            std::backtrace::Backtrace::force_capture(),
+        );
+        let path = crash_report_path();
+        match std::fs::write(&path, report) {
+            Ok(()) => eprintln!(
+                "A crash report was written to {}. Please attach it when reporting this issue.",
+                path.display()
+            ),
+            Err(e) => eprintln!("Failed to write a crash report: {}", e),
+        }
+    }
 }
 
 #[cfg(feature = "calculator-build")]
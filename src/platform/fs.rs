@@ -1,22 +1,27 @@
 #[cfg(feature = "calculator-build")]
 mod calculator {
     use crate::prelude::*;
-    // We need EXT_SAVEFILE here for the calculator-specific stem logic
-    use crate::saves::EXT_SAVEFILE;
     use ndless::alloc::string::String;
     use ndless::alloc::vec::Vec;
     use ndless::fs::File;
     use ndless::io::prelude::*;
+    use ndless::io::SeekFrom;
 
-    /// Returns the directory for save files on the calculator.
-    pub fn get_dir() -> String {
+    /// Returns the default directory for save files on the calculator.
+    pub fn default_dir() -> String {
         String::from("/documents")
     }
 
+    /// Returns the path of the settings file, next to the saves.
+    /// Files on the calculator need the `.tns` extension to show up and be transferable.
+    pub fn settings_path() -> String {
+        String::from("/documents/ntradeadvance.cfg.tns")
+    }
+
     /// Reads the directory entries at the given path.
     pub fn read_dir(path: &str) -> Result<Vec<String>> {
         ndless::fs::read_dir(path)
-            .map_err(|e| AppError::FsError(format!("Failed to read directory: {}", e)))
+            .map_err(|e| fs_error("read directory", path, e))
             .map(|entries| {
                 entries
                     .filter_map(|entry| entry.ok())
@@ -25,37 +30,87 @@ mod calculator {
             })
     }
 
+    /// Returns true if a file or directory exists at the path.
+    pub fn file_exists(path: &str) -> bool {
+        ndless::fs::metadata(path).is_ok()
+    }
+
+    /// Returns true if the path points to a directory.
+    pub fn is_dir(path: &str) -> bool {
+        ndless::fs::metadata(path)
+            .map(|m| m.is_dir())
+            .unwrap_or(false)
+    }
+
     /// Reads the contents of a file.
     pub fn read_file(path: &str) -> Result<Vec<u8>> {
-        let mut file = File::open(path)
-            .map_err(|e| AppError::FsError(format!("Failed to open file: {}", e)))?;
+        let mut file = File::open(path).map_err(|e| fs_error("open", path, e))?;
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)
-            .map_err(|e| AppError::FsError(format!("Failed to read file: {}", e)))?;
+            .map_err(|e| fs_error("read", path, e))?;
+        Ok(buffer)
+    }
+
+    /// Returns the size of a file in bytes.
+    pub fn file_size(path: &str) -> Result<u64> {
+        ndless::fs::metadata(path)
+            .map(|m| m.len())
+            .map_err(|e| fs_error("read the size of", path, e))
+    }
+
+    /// Reads the given ranges of a file, as (offset, length), one after another into one buffer.
+    pub fn read_file_ranges(path: &str, ranges: &[(u64, usize)]) -> Result<Vec<u8>> {
+        let mut file = File::open(path).map_err(|e| fs_error("open", path, e))?;
+        let mut buffer = Vec::new();
+        for &(offset, len) in ranges {
+            let start = buffer.len();
+            buffer.resize(start + len, 0);
+            file.seek(SeekFrom::Start(offset))
+                .map_err(|e| fs_error("seek in", path, e))?;
+            file.read_exact(&mut buffer[start..])
+                .map_err(|e| fs_error("read", path, e))?;
+        }
         Ok(buffer)
     }
 
     /// Writes data to a file.
     pub fn write_file(path: &str, buf: &[u8]) -> Result<()> {
-        let mut file = File::create(path)
-            .map_err(|e| AppError::FsError(format!("Failed to create file: {}", e)))?;
+        let mut file = File::create(path).map_err(|e| fs_error("create", path, e))?;
         file.write_all(buf)
-            .map_err(|e| AppError::FsError(format!("Failed to write file: {}", e)))?;
+            .map_err(|e| fs_error("write", path, e))?;
         Ok(())
     }
 
-    /// Gets the file stem (name without extension) using calculator path logic.
-    pub fn get_file_basename(full_path: &str) -> String {
-        let filename = full_path.rsplit('/').next().unwrap_or(full_path);
-        filename
-            .strip_suffix(EXT_SAVEFILE)
-            .unwrap_or(filename)
+    /// The file system of the calculator doesn't report error kinds, only messages.
+    fn fs_error(operation: &'static str, path: &str, e: impl core::fmt::Display) -> AppError {
+        AppError::FsError {
+            operation,
+            path: path.to_string(),
+            kind: e.to_string(),
+        }
+    }
+
+    /// Returns the last component of a path, including the extension.
+    pub fn file_name(full_path: &str) -> String {
+        full_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(full_path)
             .to_string()
     }
 
+    /// Returns the directory that contains the given path, if there is one.
+    pub fn parent_dir(path: &str) -> Option<String> {
+        match path.trim_end_matches('/').rsplit_once('/') {
+            Some(("", _)) => Some(String::from("/")),
+            Some((parent, _)) => Some(parent.to_string()),
+            None => None,
+        }
+    }
+
     /// Joins path components using calculator path logic (forward slashes).
     pub fn path_join(base_dir: &str, filename_with_ext: &str) -> String {
-        format!("{}/{}", base_dir, filename_with_ext)
+        format!("{}/{}", base_dir.trim_end_matches('/'), filename_with_ext)
     }
 }
 
@@ -63,10 +118,11 @@ mod calculator {
 mod desktop {
     use crate::prelude::*;
     use std::fs;
+    use std::io::{Read, Seek, SeekFrom};
     use std::path::{Path, PathBuf};
 
-    /// Returns the standard directory based on the platform.
-    pub fn get_dir() -> String {
+    /// Returns the default directory for save files, based on the platform.
+    pub fn default_dir() -> String {
         #[cfg(any(target_os = "windows", target_os = "macos"))]
         {
             dirs::desktop_dir()
@@ -82,10 +138,30 @@ mod desktop {
         }
     }
 
+    /// Returns the path of the settings file in the user's config directory.
+    pub fn settings_path() -> String {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("ntradeadvance")
+            .join("settings.cfg")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Returns true if a file or directory exists at the path.
+    pub fn file_exists(path: &str) -> bool {
+        Path::new(path).exists()
+    }
+
+    /// Returns true if the path points to a directory.
+    pub fn is_dir(path: &str) -> bool {
+        Path::new(path).is_dir()
+    }
+
     /// Reads directory entries at the given path.
     pub fn read_dir(path: &str) -> Result<Vec<String>> {
         fs::read_dir(Path::new(path))
-            .map_err(|e| AppError::FsError(e.to_string()))
+            .map_err(|e| fs_error("read directory", path, e))
             .map(|entries| {
                 entries
                     .filter_map(|entry| entry.ok().map(|e| e.path().to_string_lossy().into_owned()))
@@ -95,26 +171,61 @@ mod desktop {
 
     /// Reads a file's contents.
     pub fn read_file(path: &str) -> Result<Vec<u8>> {
-        fs::read(Path::new(path)).map_err(|e| AppError::FsError(e.to_string()))
+        fs::read(Path::new(path)).map_err(|e| fs_error("read", path, e))
     }
 
-    /// Writes data to a file.
+    /// Returns the size of a file in bytes.
+    pub fn file_size(path: &str) -> Result<u64> {
+        fs::metadata(Path::new(path))
+            .map(|m| m.len())
+            .map_err(|e| fs_error("read the size of", path, e))
+    }
+
+    /// Reads the given ranges of a file, as (offset, length), one after another into one buffer.
+    pub fn read_file_ranges(path: &str, ranges: &[(u64, usize)]) -> Result<Vec<u8>> {
+        let mut file = fs::File::open(Path::new(path)).map_err(|e| fs_error("open", path, e))?;
+        let mut buffer = Vec::new();
+        for &(offset, len) in ranges {
+            let start = buffer.len();
+            buffer.resize(start + len, 0);
+            file.seek(SeekFrom::Start(offset))
+                .map_err(|e| fs_error("seek in", path, e))?;
+            file.read_exact(&mut buffer[start..])
+                .map_err(|e| fs_error("read", path, e))?;
+        }
+        Ok(buffer)
+    }
+
+    /// Writes data to a file. Missing parent directories are created.
     pub fn write_file(path: &str, buf: &[u8]) -> Result<()> {
-        fs::write(Path::new(path), buf).map_err(|e| AppError::FsError(e.to_string()))
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).map_err(|e| fs_error("create the directory of", path, e))?;
+        }
+        fs::write(Path::new(path), buf).map_err(|e| fs_error("write", path, e))
+    }
+
+    fn fs_error(operation: &'static str, path: &str, e: std::io::Error) -> AppError {
+        AppError::FsError {
+            operation,
+            path: path.to_string(),
+            kind: e.kind().to_string(),
+        }
     }
 
-    /// Gets the file stem (name without extension) using standard library path logic.
-    pub fn get_file_basename(full_path: &str) -> String {
+    /// Returns the last component of a path, including the extension.
+    pub fn file_name(full_path: &str) -> String {
         Path::new(full_path)
-            .file_stem()
+            .file_name()
             .map(|s| s.to_string_lossy().into_owned())
-            // Fallback: if no stem (e.g., hidden file ".sav"), use the filename itself
-            .unwrap_or_else(|| {
-                Path::new(full_path)
-                    .file_name()
-                    .map(|s| s.to_string_lossy().into_owned())
-                    .unwrap_or_else(|| full_path.to_string()) // Final fallback to full path if needed
-            })
+            .unwrap_or_else(|| full_path.to_string())
+    }
+
+    /// Returns the directory that contains the given path, if there is one.
+    pub fn parent_dir(path: &str) -> Option<String> {
+        Path::new(path)
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_string_lossy().into_owned())
     }
 
     /// Joins path components using standard library path logic.
@@ -5,44 +5,148 @@ pub enum InputKey {
     Right,
     Enter,
     Escape,
+    /// Deletes the character before the cursor.
+    Backspace,
+    /// A printable character (letters, digits, punctuation and space).
+    Char(char),
+    /// A tap or click on the cell at (column, row).
+    /// Screens find out what was tapped with `hit_test`.
+    Pointer(u16, u16),
+    /// The screen size changed and the current screen needs to be laid out again.
+    /// Only emitted on desktop.
+    #[cfg_attr(feature = "calculator-build", allow(dead_code))]
+    Resize,
 }
 
 #[cfg(feature = "calculator-build")]
 mod calculator {
     use super::InputKey;
+    use crate::platform::sleep::sleep;
+    use ndless::input::any_key_pressed;
     use ndless::input::get_keys;
-    use ndless::input::touchpad::touchpad_scan;
-    use ndless::input::wait_key_pressed;
+    use ndless::input::touchpad::{touchpad_info, touchpad_scan};
     use ndless::input::Key;
 
-    /// Waits for and returns an input key on the calculator.
-    pub fn wait_input() -> InputKey {
-        use ndless::input::wait_no_key_pressed;
+    /// Keypad keys that produce a character, in their unshifted form.
+    /// Letters are upper-cased while shift is held.
+    const CHAR_KEYS: [(Key, char); 38] = [
+        (Key::A, 'a'),
+        (Key::B, 'b'),
+        (Key::C, 'c'),
+        (Key::D, 'd'),
+        (Key::E, 'e'),
+        (Key::F, 'f'),
+        (Key::G, 'g'),
+        (Key::H, 'h'),
+        (Key::I, 'i'),
+        (Key::J, 'j'),
+        (Key::K, 'k'),
+        (Key::L, 'l'),
+        (Key::M, 'm'),
+        (Key::N, 'n'),
+        (Key::O, 'o'),
+        (Key::P, 'p'),
+        (Key::Q, 'q'),
+        (Key::R, 'r'),
+        (Key::S, 's'),
+        (Key::T, 't'),
+        (Key::U, 'u'),
+        (Key::V, 'v'),
+        (Key::W, 'w'),
+        (Key::X, 'x'),
+        (Key::Y, 'y'),
+        (Key::Z, 'z'),
+        (Key::Key0, '0'),
+        (Key::Key1, '1'),
+        (Key::Key2, '2'),
+        (Key::Key3, '3'),
+        (Key::Key4, '4'),
+        (Key::Key5, '5'),
+        (Key::Key6, '6'),
+        (Key::Key7, '7'),
+        (Key::Key8, '8'),
+        (Key::Key9, '9'),
+        (Key::Space, ' '),
+        (Key::Minus, '-'),
+    ];
+
+    /// Whether the keys that are currently held down were already reported.
+    static mut HELD: bool = false;
+
+    /// Maps a touchpad position to a cell of the screen.
+    /// The touchpad covers the whole screen, with its origin in the bottom-left corner.
+    fn touchpad_cell(x: u16, y: u16) -> Option<InputKey> {
+        let info = touchpad_info().ok()?;
+        let (cols, rows) = crate::platform::console::size();
+        let col = x as u32 * cols as u32 / (info.width as u32).max(1);
+        let row =
+            (info.height.saturating_sub(y)) as u32 * rows as u32 / (info.height as u32).max(1);
+        Some(InputKey::Pointer(
+            col.min(cols.saturating_sub(1) as u32) as u16,
+            row.min(rows.saturating_sub(1) as u32) as u16,
+        ))
+    }
+
+    /// Decodes the keys that are currently pressed, without waiting.
+    fn read_keys() -> Option<InputKey> {
+        let touchpad = touchpad_scan();
+        if touchpad.is_ok() {
+            let touchpad = touchpad.unwrap();
+            if touchpad.pressed {
+                let input = match touchpad.arrow {
+                    Some(Key::Up) => Some(InputKey::Up),
+                    Some(Key::Down) => Some(InputKey::Down),
+                    Some(Key::Left) => Some(InputKey::Left),
+                    Some(Key::Right) => Some(InputKey::Right),
+                    // Clicking the middle of the touchpad taps the cell under the finger.
+                    _ if touchpad.contact => touchpad_cell(touchpad.x, touchpad.y),
+                    _ => None,
+                };
+                if input.is_some() {
+                    return input;
+                }
+            }
+        }
+        let keys = get_keys();
+        if keys.contains(&Key::Esc) {
+            return Some(InputKey::Escape);
+        } else if keys.contains(&Key::Enter) {
+            return Some(InputKey::Enter);
+        } else if keys.contains(&Key::Del) {
+            return Some(InputKey::Backspace);
+        }
+        let shift = keys.contains(&Key::Shift);
+        CHAR_KEYS
+            .iter()
+            .find(|(key, _)| keys.contains(key))
+            .map(|(_, ch)| InputKey::Char(if shift { ch.to_ascii_uppercase() } else { *ch }))
+    }
+
+    /// Waits up to `timeout_ms` for a key press and returns it, if any.
+    ///
+    /// Only the moment a key goes down is reported, holding it doesn't repeat.
+    pub fn poll_input(timeout_ms: u32) -> Option<InputKey> {
+        /// How often the keypad is scanned while waiting.
+        const SCAN_INTERVAL: u32 = 5;
+
+        let mut waited = 0;
         loop {
-            wait_key_pressed();
-            let touchpad = touchpad_scan();
-            if touchpad.is_ok() {
-                let touchpad = touchpad.unwrap();
-                if touchpad.pressed {
-                    let input = match touchpad.arrow {
-                        Some(Key::Up) => Some(InputKey::Up),
-                        Some(Key::Down) => Some(InputKey::Down),
-                        Some(Key::Left) => Some(InputKey::Left),
-                        Some(Key::Right) => Some(InputKey::Right),
-                        _ => None,
-                    };
-                    if let Some(key) = input {
-                        return key;
+            if any_key_pressed() {
+                // SAFETY: Input is only read from the UI thread.
+                if unsafe { !HELD } {
+                    unsafe { HELD = true };
+                    if let Some(key) = read_keys() {
+                        return Some(key);
                     }
                 }
+            } else {
+                unsafe { HELD = false };
             }
-            let keys = get_keys();
-            if keys.contains(&Key::Esc) {
-                return InputKey::Escape;
-            } else if keys.contains(&Key::Enter) {
-                return InputKey::Enter;
+            if waited >= timeout_ms {
+                return None;
             }
-            wait_no_key_pressed();
+            sleep(SCAN_INTERVAL);
+            waited += SCAN_INTERVAL;
         }
     }
 }
@@ -50,38 +154,66 @@ mod calculator {
 #[cfg(feature = "desktop")]
 mod desktop {
     use super::InputKey;
+    use crate::platform::console;
     use core::time::Duration;
-    use crossterm::event::{poll, read, Event, KeyCode, KeyEvent, KeyEventKind};
-    use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-
-    /// Waits for and returns an input key from the terminal,
-    /// reacting only to keydown (press) events.
-    pub fn wait_input() -> InputKey {
-        enable_raw_mode().expect("Failed to enable raw mode");
+    use crossterm::event::{
+        poll, read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+        MouseEventKind,
+    };
+    use std::time::Instant;
 
-        // Discards any buffered input events.
-        while poll(Duration::from_millis(0)).unwrap() {
-            let _ = read();
+    /// Maps a terminal event to an input key.
+    /// Only key press events are reported, releases and repeats are ignored.
+    fn map_event(event: Event) -> Option<InputKey> {
+        match event {
+            Event::Resize(_, _) => {
+                console::invalidate();
+                Some(InputKey::Resize)
+            }
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) => Some(InputKey::Pointer(column, row)),
+            // Raw mode turns Ctrl+C into a key event instead of a signal.
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) if modifiers.contains(KeyModifiers::CONTROL) => console::interrupt(),
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) => match code {
+                KeyCode::Up => Some(InputKey::Up),
+                KeyCode::Down => Some(InputKey::Down),
+                KeyCode::Left => Some(InputKey::Left),
+                KeyCode::Right => Some(InputKey::Right),
+                KeyCode::Enter => Some(InputKey::Enter),
+                KeyCode::Esc => Some(InputKey::Escape),
+                KeyCode::Backspace => Some(InputKey::Backspace),
+                KeyCode::Char(c) => Some(InputKey::Char(c)),
+                _ => None,
+            },
+            _ => None,
         }
+    }
 
-        let input = loop {
-            if let Ok(Event::Key(KeyEvent { code, kind, .. })) = read() {
-                // Only react if this is a key press event.
-                if kind == KeyEventKind::Press {
-                    match code {
-                        KeyCode::Up => break InputKey::Up,
-                        KeyCode::Down => break InputKey::Down,
-                        KeyCode::Left => break InputKey::Left,
-                        KeyCode::Right => break InputKey::Right,
-                        KeyCode::Enter => break InputKey::Enter,
-                        KeyCode::Esc => break InputKey::Escape,
-                        _ => {}
-                    }
-                }
+    /// Waits up to `timeout_ms` for a key press and returns it, if any.
+    pub fn poll_input(timeout_ms: u32) -> Option<InputKey> {
+        let timeout = Duration::from_millis(timeout_ms as u64);
+        let start = Instant::now();
+        let mut input = None;
+        while input.is_none() {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if !poll(remaining).unwrap_or(false) {
+                break;
             }
-        };
-
-        disable_raw_mode().expect("Failed to disable raw mode");
+            input = read().ok().and_then(map_event);
+        }
         input
     }
 }
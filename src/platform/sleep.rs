@@ -1,22 +1,45 @@
 #[cfg(feature = "calculator-build")]
 mod calculator {
+    /// Milliseconds slept since the start of the program.
+    static mut CLOCK: u32 = 0;
+
     #[cfg(feature = "calculator-build")]
     /// Sleeps for the specified number of milliseconds on the calculator.
     pub fn sleep(ms: u32) {
-        unsafe { ndless_sys::msleep(ms) };
+        unsafe {
+            ndless_sys::msleep(ms);
+            CLOCK = CLOCK.wrapping_add(ms);
+        }
+    }
+
+    /// Returns a monotonic timestamp in milliseconds.
+    ///
+    /// There's no millisecond clock on the calculator, so time only advances while sleeping.
+    /// The UI loop sleeps between frames, so this is close enough for animations.
+    pub fn now_ms() -> u32 {
+        unsafe { CLOCK }
     }
 }
 
 #[cfg(not(feature = "calculator-build"))]
 mod desktop {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
     /// Sleeps for the specified number of milliseconds on desktop platforms.
     pub fn sleep(ms: u32) {
         std::thread::sleep(std::time::Duration::from_millis(ms as u64));
     }
+
+    /// Returns a monotonic timestamp in milliseconds.
+    pub fn now_ms() -> u32 {
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_millis() as u32
+    }
 }
 
 #[cfg(feature = "calculator-build")]
-pub use calculator::sleep;
+pub use calculator::{now_ms, sleep};
 
 #[cfg(not(feature = "calculator-build"))]
-pub use desktop::sleep;
+pub use desktop::{now_ms, sleep};
@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// Offsets of the Pokedex flags in the trainer info. Both are bit fields indexed by the
+/// National Pokedex number minus one.
+const OFFSET_CAUGHT: usize = 0x28;
+const OFFSET_SEEN: usize = 0x5C;
+/// The size of a Pokedex bit field, rounded up to whole bytes.
+const FLAGS_SIZE: usize = NATIONAL_DEX_SIZE.div_ceil(8);
+
+/// The Pokedex of a save.
+#[derive(Clone, Debug)]
+pub struct Pokedex {
+    seen: Vec<u8>,
+    caught: Vec<u8>,
+}
+
+impl Pokedex {
+    pub fn read(blocks: &SaveBlocks) -> Self {
+        Self {
+            seen: blocks.trainer[OFFSET_SEEN..OFFSET_SEEN + FLAGS_SIZE].to_vec(),
+            caught: blocks.trainer[OFFSET_CAUGHT..OFFSET_CAUGHT + FLAGS_SIZE].to_vec(),
+        }
+    }
+
+    pub fn is_seen(&self, species: u16) -> bool {
+        is_flagged(&self.seen, species)
+    }
+
+    pub fn is_caught(&self, species: u16) -> bool {
+        is_flagged(&self.caught, species)
+    }
+
+    pub fn seen_count(&self) -> usize {
+        (1..=NATIONAL_DEX_SIZE as u16)
+            .filter(|&s| self.is_seen(s))
+            .count()
+    }
+
+    pub fn caught_count(&self) -> usize {
+        (1..=NATIONAL_DEX_SIZE as u16)
+            .filter(|&s| self.is_caught(s))
+            .count()
+    }
+}
+
+fn is_flagged(flags: &[u8], species: u16) -> bool {
+    let Some(i) = (species as usize).checked_sub(1) else {
+        return false;
+    };
+    i < NATIONAL_DEX_SIZE && flags[i / 8] & (1 << (i % 8)) != 0
+}
+
+/// A species that one save has caught and the other one still needs.
+#[derive(Clone, Debug)]
+pub struct DexGap {
+    /// The National Pokedex number.
+    pub species: u16,
+    /// Where the save that has caught the species keeps Pokemon of it.
+    /// Empty if it has traded or released all of them.
+    pub sources: Vec<Location>,
+}
+
+/// Compares the Pokedexes of two saves. Returns the species that `from` has caught and
+/// `to` hasn't, together with the Pokemon of `from` that would fill each gap.
+pub fn dex_gaps(from: &SaveBlocks, to: &SaveBlocks) -> Vec<DexGap> {
+    let from_dex = Pokedex::read(from);
+    let to_dex = Pokedex::read(to);
+    // Eggs don't count for the Pokedex until they hatch.
+    let pokemon: Vec<StoredPokemon> = stored_pokemon(from)
+        .into_iter()
+        .filter(|p| !p.is_egg)
+        .collect();
+
+    (1..=NATIONAL_DEX_SIZE as u16)
+        .filter(|&species| from_dex.is_caught(species) && !to_dex.is_caught(species))
+        .map(|species| DexGap {
+            species,
+            sources: pokemon
+                .iter()
+                .filter(|p| p.species == species)
+                .map(|p| p.location)
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save_data::tests::{blank_blocks, pokemon, put_in_box};
+
+    fn catch(blocks: &mut SaveBlocks, species: u16) {
+        let i = species as usize - 1;
+        blocks.trainer[OFFSET_CAUGHT + i / 8] |= 1 << (i % 8);
+        blocks.trainer[OFFSET_SEEN + i / 8] |= 1 << (i % 8);
+    }
+
+    #[test]
+    fn reads_the_flags() {
+        let mut blocks = blank_blocks(GameVersion::Emerald);
+        catch(&mut blocks, 1);
+        catch(&mut blocks, 386);
+        let dex = Pokedex::read(&blocks);
+        assert!(dex.is_caught(1) && dex.is_caught(386) && dex.is_seen(386));
+        assert!(!dex.is_caught(2));
+        assert!(!dex.is_caught(0) && !dex.is_caught(387));
+        assert_eq!(dex.caught_count(), 2);
+    }
+
+    #[test]
+    fn finds_the_gaps_and_where_to_fill_them() {
+        let mut from = blank_blocks(GameVersion::FireRedLeafGreen);
+        let mut to = blank_blocks(GameVersion::RubySapphire);
+        for species in [1, 4, 7] {
+            catch(&mut from, species);
+        }
+        catch(&mut to, 4);
+        put_in_box(&mut from, 0, 3, &pokemon(7));
+        let mut egg = pokemon(7);
+        egg.is_egg = true;
+        put_in_box(&mut from, 1, 0, &egg);
+
+        let gaps = dex_gaps(&from, &to);
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].species, 1);
+        assert!(gaps[0].sources.is_empty());
+        assert_eq!(gaps[1].species, 7);
+        // The egg doesn't count until it hatches.
+        assert_eq!(gaps[1].sources, [Location::Box(0, 3)]);
+        assert!(dex_gaps(&to, &from).is_empty());
+    }
+}
@@ -15,15 +15,29 @@ pub use ndless::math::Float;
 
 pub use crate::assets::*;
 pub use crate::errors::*;
+pub use crate::i18n::*;
+pub use crate::items::*;
+pub use crate::mail::*;
 pub use crate::navigator::*;
 pub use crate::platform::console;
 pub use crate::platform::fs::*;
 pub use crate::platform::input::*;
 pub use crate::platform::sleep::*;
+pub use crate::pokedex::*;
+pub use crate::save_data::*;
+#[cfg(not(feature = "calculator-build"))]
+pub use crate::save_diff::*;
 pub use crate::saves::*;
-pub use crate::ui::rendering::render_ui;
+pub use crate::search::*;
+pub use crate::settings::*;
+pub use crate::species::*;
+pub use crate::trainer_card::*;
+pub use crate::ui::animation::*;
+pub use crate::ui::focus::*;
+pub use crate::ui::rendering::{hit_test, render_ui, Hit};
 pub use crate::ui::screens::*;
 pub use crate::ui::theme::*;
 pub use crate::ui::widgets::*;
+pub use crate::verify::*;
 pub use crate::widget_vec;
 pub use core::cmp::*;
@@ -0,0 +1,900 @@
+use crate::prelude::*;
+
+/// The number of sectors in a save slot.
+pub const SLOT_SECTORS: usize = 14;
+/// The number of bytes of a sector that hold data, the rest is unused or the footer.
+pub const SECTOR_DATA_SIZE: usize = 0xF80;
+
+/// The number of boxes in the PC.
+pub const BOX_COUNT: usize = 14;
+/// The number of Pokemon in a box.
+pub const BOX_SIZE: usize = 30;
+/// The number of Pokemon in a full party.
+pub const PARTY_SIZE: usize = 6;
+/// The size of a Pokemon in a box. Party Pokemon have their stats appended to it.
+const BOX_POKEMON_SIZE: usize = 80;
+pub const PARTY_POKEMON_SIZE: usize = 100;
+/// Offset of the level in the stats of a party Pokemon.
+const OFFSET_LEVEL: usize = 84;
+/// Offset of the boxes in the PC storage, after the number of the current box.
+const OFFSET_BOXES: usize = 4;
+/// Offset of the game code in the trainer info.
+const OFFSET_GAME_CODE: usize = 0xAC;
+/// The size of the PC storage, which is the same in every game.
+const STORAGE_SIZE: usize = 0x83D0;
+
+/// The Gen 3 games, as far as their saves can tell them apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameVersion {
+    RubySapphire,
+    FireRedLeafGreen,
+    Emerald,
+}
+
+impl GameVersion {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameVersion::RubySapphire => "Ruby/Sapphire",
+            GameVersion::FireRedLeafGreen => "FireRed/LeafGreen",
+            GameVersion::Emerald => "Emerald",
+        }
+    }
+
+    /// The name shown in the user interface.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            GameVersion::RubySapphire => Text::GameRubySapphire,
+            GameVersion::FireRedLeafGreen => Text::GameFireRedLeafGreen,
+            GameVersion::Emerald => Text::GameEmerald,
+        })
+    }
+
+    /// Offset of the number of party Pokemon in the game state. The party follows it.
+    pub fn party_offset(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 0x234,
+            GameVersion::FireRedLeafGreen => 0x34,
+        }
+    }
+
+    /// Offset of the mail in the game state. The first slots belong to the party,
+    /// the others to the PC.
+    pub fn mail_offset(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x2B4C,
+            GameVersion::FireRedLeafGreen => 0x2CD0,
+            GameVersion::Emerald => 0x2BE0,
+        }
+    }
+
+    /// Offset of the money in the game state.
+    pub fn money_offset(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 0x490,
+            GameVersion::FireRedLeafGreen => 0x290,
+        }
+    }
+
+    /// Offset of the event flags in the game state.
+    pub fn flags_offset(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x1220,
+            GameVersion::FireRedLeafGreen => 0xEE0,
+            GameVersion::Emerald => 0x1270,
+        }
+    }
+
+    /// The flag of the first gym badge. The other badges follow it.
+    pub fn first_badge_flag(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x807,
+            GameVersion::FireRedLeafGreen => 0x820,
+            GameVersion::Emerald => 0x867,
+        }
+    }
+
+    /// The sizes of the trainer info and the game state.
+    fn block_sizes(&self) -> (usize, usize) {
+        match self {
+            GameVersion::RubySapphire => (0x890, 0x3AC0),
+            GameVersion::FireRedLeafGreen => (0xF24, 0x3D68),
+            GameVersion::Emerald => (0xF2C, 0x3D88),
+        }
+    }
+
+    /// The number of bytes of the sector with the given id that hold data. The checksum
+    /// of the sector covers exactly these, the rest of the sector is left as it was.
+    pub fn sector_size(&self, id: usize) -> usize {
+        let (trainer, state) = self.block_sizes();
+        // The blocks are split into sectors of `SECTOR_DATA_SIZE` bytes, the last one is shorter.
+        let part = |size: usize, first: usize| {
+            size.saturating_sub((id - first) * SECTOR_DATA_SIZE)
+                .min(SECTOR_DATA_SIZE)
+        };
+        match id {
+            0 => trainer,
+            1..=4 => part(state, 1),
+            _ => part(STORAGE_SIZE, 5),
+        }
+    }
+
+    /// The flag that is set once the player entered the Hall of Fame.
+    pub fn game_clear_flag(&self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x804,
+            GameVersion::FireRedLeafGreen => 0x82C,
+            GameVersion::Emerald => 0x864,
+        }
+    }
+}
+
+/// The current save of a save file, split into the blocks the game stores it in.
+///
+/// `pkmn_savedata` only gives access to the party, so everything else is read from these.
+#[derive(Clone, Debug)]
+pub struct SaveBlocks {
+    pub version: GameVersion,
+    /// The trainer info and the Pokedex (sector 0).
+    pub trainer: Vec<u8>,
+    /// The game state: party, items, money and flags (sectors 1 to 4).
+    pub state: Vec<u8>,
+    /// The PC boxes (sectors 5 to 13).
+    pub storage: Vec<u8>,
+}
+
+impl SaveBlocks {
+    /// Returns true if the event flag with the given index is set.
+    pub fn flag(&self, flag: usize) -> bool {
+        let byte = self.version.flags_offset() + flag / 8;
+        self.state
+            .get(byte)
+            .is_some_and(|b| b & (1 << (flag % 8)) != 0)
+    }
+}
+
+/// Where a Pokemon is kept in a save.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    /// A slot of the party.
+    Party(usize),
+    /// A box of the PC and the slot in it.
+    Box(usize, usize),
+}
+
+impl core::fmt::Display for Location {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Location::Party(_) => write!(f, "Party"),
+            Location::Box(b, _) => write!(f, "Box {}", b + 1),
+        }
+    }
+}
+
+/// The natures, in the order of their index: the personality modulo 25.
+pub const NATURES: [&str; 25] = [
+    "Hardy", "Lonely", "Brave", "Adamant", "Naughty", "Bold", "Docile", "Relaxed", "Impish", "Lax",
+    "Timid", "Hasty", "Serious", "Jolly", "Naive", "Modest", "Mild", "Quiet", "Bashful", "Rash",
+    "Calm", "Gentle", "Sassy", "Careful", "Quirky",
+];
+
+/// The natures in the user interface, in the order of `NATURES`.
+const NATURE_TEXTS: [Text; 25] = [
+    Text::NatureHardy,
+    Text::NatureLonely,
+    Text::NatureBrave,
+    Text::NatureAdamant,
+    Text::NatureNaughty,
+    Text::NatureBold,
+    Text::NatureDocile,
+    Text::NatureRelaxed,
+    Text::NatureImpish,
+    Text::NatureLax,
+    Text::NatureTimid,
+    Text::NatureHasty,
+    Text::NatureSerious,
+    Text::NatureJolly,
+    Text::NatureNaive,
+    Text::NatureModest,
+    Text::NatureMild,
+    Text::NatureQuiet,
+    Text::NatureBashful,
+    Text::NatureRash,
+    Text::NatureCalm,
+    Text::NatureGentle,
+    Text::NatureSassy,
+    Text::NatureCareful,
+    Text::NatureQuirky,
+];
+
+/// Returns the name of the nature with the given index in the language of the user interface.
+pub fn nature_label(index: usize) -> &'static str {
+    tr(NATURE_TEXTS[index])
+}
+
+/// A Pokemon of the party or the PC, as far as it is needed to find and plan trades.
+#[derive(Clone, Debug)]
+pub struct StoredPokemon {
+    pub location: Location,
+    /// The National Pokedex number of the species.
+    pub species: u16,
+    pub personality: u32,
+    /// The trainer id of the original trainer, with the secret id in the upper half.
+    pub ot_id: u32,
+    pub nickname: String,
+    pub ot_name: String,
+    /// The id of the held item, 0 if the Pokemon holds nothing.
+    pub held_item: u16,
+    /// Only known for party Pokemon. The boxes store the experience instead.
+    pub level: Option<u8>,
+    /// The friendship. For eggs, the number of step cycles until they hatch.
+    pub friendship: u8,
+    pub is_egg: bool,
+    /// Data that the game doesn't accept, e.g. because the checksum doesn't match.
+    /// Everything but the location is unreliable.
+    pub is_bad_egg: bool,
+}
+
+impl StoredPokemon {
+    fn bad_egg(data: &[u8], location: Location) -> Self {
+        StoredPokemon {
+            location,
+            species: 0,
+            personality: read_u32(data, 0),
+            ot_id: read_u32(data, 4),
+            nickname: String::new(),
+            ot_name: String::new(),
+            held_item: 0,
+            level: None,
+            friendship: 0,
+            is_egg: true,
+            is_bad_egg: true,
+        }
+    }
+
+    /// The name shown in lists: the species, "Egg" or "Bad Egg", marked if shiny.
+    pub fn label(&self) -> String {
+        if self.is_bad_egg {
+            String::from(tr(Text::BadEgg))
+        } else if self.is_egg {
+            // The game doesn't reveal the species before the egg hatches.
+            String::from(tr(Text::Egg))
+        } else if self.is_shiny() {
+            format!("{} *", species_name(self.species))
+        } else {
+            species_name(self.species)
+        }
+    }
+
+    /// Returns true if both are the same Pokemon in the same state, wherever they are kept.
+    pub fn is_same(&self, other: &StoredPokemon) -> bool {
+        self.species == other.species
+            && self.personality == other.personality
+            && self.ot_id == other.ot_id
+            && self.nickname == other.nickname
+            && self.ot_name == other.ot_name
+            && self.held_item == other.held_item
+            && self.level == other.level
+            && self.is_egg == other.is_egg
+            && self.is_bad_egg == other.is_bad_egg
+    }
+
+    pub fn is_shiny(&self) -> bool {
+        let value = (self.ot_id >> 16) ^ (self.ot_id & 0xFFFF);
+        let value = value ^ (self.personality >> 16) ^ (self.personality & 0xFFFF);
+        value < 8
+    }
+
+    /// The index of the nature in `NATURES`.
+    pub fn nature(&self) -> usize {
+        (self.personality % 25) as usize
+    }
+}
+
+/// Splits the current save of a save file into its blocks.
+/// Returns None if a sector of the current save is missing.
+pub fn parse_save_blocks(buf: &[u8]) -> Option<SaveBlocks> {
+    let sectors = current_sectors(buf)?;
+    let join = |range: core::ops::Range<usize>| {
+        let mut block = Vec::with_capacity(range.len() * SECTOR_DATA_SIZE);
+        for sector in &sectors[range] {
+            block.extend_from_slice(&sector[..SECTOR_DATA_SIZE]);
+        }
+        block
+    };
+
+    let trainer = join(0..1);
+    let version = game_version(&trainer);
+    Some(SaveBlocks {
+        version,
+        trainer,
+        state: join(1..5),
+        storage: join(5..SLOT_SECTORS),
+    })
+}
+
+/// Tells the games apart by the game code in the trainer info.
+fn game_version(trainer: &[u8]) -> GameVersion {
+    match read_u32(trainer, OFFSET_GAME_CODE) {
+        0 => GameVersion::RubySapphire,
+        1 => GameVersion::FireRedLeafGreen,
+        // Emerald stores its security key here instead.
+        _ => GameVersion::Emerald,
+    }
+}
+
+/// Returns the sectors of the most recent save slot, ordered by their ids.
+fn current_sectors(buf: &[u8]) -> Option<Vec<&[u8]>> {
+    let offsets = current_sector_offsets(buf)?;
+    Some(
+        offsets
+            .into_iter()
+            .map(|offset| &buf[offset..offset + SECTOR_SIZE])
+            .collect(),
+    )
+}
+
+/// Returns the file offsets of the sectors of the most recent save slot, ordered by their ids.
+///
+/// The game alternates between two slots and rotates the sectors within a slot,
+/// so the order in the file says nothing about their content.
+pub fn current_sector_offsets(buf: &[u8]) -> Option<Vec<usize>> {
+    if buf.len() < SAVE_SIZE {
+        return None;
+    }
+    let sector = |i: usize| &buf[i * SECTOR_SIZE..(i + 1) * SECTOR_SIZE];
+    let save_index = |slot: usize| {
+        (0..SLOT_SECTORS)
+            .map(|i| sector(slot * SLOT_SECTORS + i))
+            .filter(|s| read_u32(s, 0xFF8) == SECTOR_SIGNATURE)
+            .map(|s| read_u32(s, 0xFFC))
+            .max()
+    };
+    let slot = match (save_index(0), save_index(1)) {
+        (Some(a), Some(b)) => usize::from(b > a),
+        (Some(_), None) => 0,
+        (None, Some(_)) => 1,
+        (None, None) => return None,
+    };
+
+    let mut offsets: Vec<Option<usize>> = vec![None; SLOT_SECTORS];
+    for i in slot * SLOT_SECTORS..(slot + 1) * SLOT_SECTORS {
+        let s = sector(i);
+        let id = u16::from_le_bytes([s[0xFF4], s[0xFF5]]) as usize;
+        if let Some(entry) = offsets.get_mut(id) {
+            *entry = Some(i * SECTOR_SIZE);
+        }
+    }
+    offsets.into_iter().collect()
+}
+
+/// Returns the save index of the most recent save slot, which the game increments with
+/// every save. Returns None if the sectors of the slot don't agree on it.
+pub fn current_save_index(buf: &[u8]) -> Option<u32> {
+    let offsets = current_sector_offsets(buf)?;
+    let index = read_u32(buf, offsets[0] + 0xFFC);
+    offsets
+        .iter()
+        .all(|&offset| read_u32(buf, offset + 0xFFC) == index)
+        .then_some(index)
+}
+
+/// Returns the ids of the sectors of the most recent save whose checksum doesn't match.
+pub fn invalid_sectors(buf: &[u8]) -> Vec<usize> {
+    let Some(offsets) = current_sector_offsets(buf) else {
+        return (0..SLOT_SECTORS).collect();
+    };
+    let version = game_version(&buf[offsets[0]..offsets[0] + SECTOR_DATA_SIZE]);
+    (0..SLOT_SECTORS)
+        .filter(|&id| {
+            let sector = &buf[offsets[id]..offsets[id] + SECTOR_SIZE];
+            sector_checksum(&sector[..version.sector_size(id)]) != stored_checksum(sector)
+        })
+        .collect()
+}
+
+/// Writes bytes into the game state of the most recent save and updates the checksums of
+/// the sectors they went into. Returns None if a sector is missing, its checksum was
+/// already wrong or the bytes don't fit into the game state, in which case the buffer may
+/// be partly written.
+pub fn write_state(buf: &mut [u8], offset: usize, bytes: &[u8]) -> Option<()> {
+    let offsets = current_sector_offsets(buf)?;
+    let version = game_version(&buf[offsets[0]..offsets[0] + SECTOR_DATA_SIZE]);
+    let mut written = 0;
+    while written < bytes.len() {
+        let position = offset + written;
+        // The game state starts with sector 1 and ends with sector 4.
+        let id = 1 + position / SECTOR_DATA_SIZE;
+        if id > 4 {
+            return None;
+        }
+        let sector = offsets[id];
+        let size = version.sector_size(id);
+        let start = position % SECTOR_DATA_SIZE;
+        if start >= size {
+            return None;
+        }
+        let len = (size - start).min(bytes.len() - written);
+
+        if sector_checksum(&buf[sector..sector + size]) != stored_checksum(&buf[sector..]) {
+            return None;
+        }
+        buf[sector + start..sector + start + len].copy_from_slice(&bytes[written..written + len]);
+        let checksum = sector_checksum(&buf[sector..sector + size]);
+        buf[sector + 0xFF6..sector + 0xFF8].copy_from_slice(&checksum.to_le_bytes());
+        written += len;
+    }
+    Some(())
+}
+
+/// Returns the checksum stored in the footer of a sector.
+fn stored_checksum(sector: &[u8]) -> u16 {
+    u16::from_le_bytes([sector[0xFF6], sector[0xFF7]])
+}
+
+/// The checksum of a sector: the sum of its data as 32 bit words, folded to 16 bits.
+fn sector_checksum(data: &[u8]) -> u16 {
+    let sum = data
+        .chunks_exact(4)
+        .fold(0u32, |sum, word| sum.wrapping_add(read_u32(word, 0)));
+    ((sum >> 16) + (sum & 0xFFFF)) as u16
+}
+
+/// Returns the Pokemon of the party. Bad eggs are included, so the slots match the party.
+pub fn party_pokemon(blocks: &SaveBlocks) -> Vec<StoredPokemon> {
+    let party_offset = blocks.version.party_offset();
+    let party_count = (read_u32(&blocks.state, party_offset) as usize).min(PARTY_SIZE);
+    (0..party_count)
+        .map(|i| {
+            let start = party_offset + 4 + i * PARTY_POKEMON_SIZE;
+            let data = &blocks.state[start..start + PARTY_POKEMON_SIZE];
+            let level = Some(data[OFFSET_LEVEL]);
+            // A party slot is never empty, so anything that doesn't decode is a bad egg.
+            decode_pokemon(data, Location::Party(i), level)
+                .unwrap_or_else(|| StoredPokemon::bad_egg(data, Location::Party(i)))
+        })
+        .collect()
+}
+
+/// Returns the Pokemon of the party and the PC boxes, including bad eggs.
+pub fn stored_pokemon(blocks: &SaveBlocks) -> Vec<StoredPokemon> {
+    let mut pokemon = party_pokemon(blocks);
+    for b in 0..BOX_COUNT {
+        for slot in 0..BOX_SIZE {
+            let start = OFFSET_BOXES + (b * BOX_SIZE + slot) * BOX_POKEMON_SIZE;
+            let data = &blocks.storage[start..start + BOX_POKEMON_SIZE];
+            pokemon.extend(decode_pokemon(data, Location::Box(b, slot), None));
+        }
+    }
+    pokemon
+}
+
+/// The positions of the growth, attacks, EVs and misc substructures for each of the 24
+/// orders they can be stored in.
+const SUBSTRUCTURE_POSITIONS: [[usize; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 3, 1, 2],
+    [0, 2, 3, 1],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [2, 0, 1, 3],
+    [3, 0, 1, 2],
+    [2, 0, 3, 1],
+    [3, 0, 2, 1],
+    [1, 2, 0, 3],
+    [1, 3, 0, 2],
+    [2, 1, 0, 3],
+    [3, 1, 0, 2],
+    [2, 3, 0, 1],
+    [3, 2, 0, 1],
+    [1, 2, 3, 0],
+    [1, 3, 2, 0],
+    [2, 1, 3, 0],
+    [3, 1, 2, 0],
+    [2, 3, 1, 0],
+    [3, 2, 1, 0],
+];
+const GROWTH: usize = 0;
+const MISC: usize = 3;
+
+/// Decodes a Pokemon in the 80 byte format of the boxes. Returns None for empty slots.
+///
+/// The 48 bytes from offset 32 hold four substructures, XOR-encrypted with the personality
+/// and the trainer id. Their order depends on the personality. The species and the held
+/// item are the first values of the growth substructure and the friendship is the second
+/// byte of its third value. The egg flag is bit 30 of the second value of the misc substructure.
+fn decode_pokemon(data: &[u8], location: Location, level: Option<u8>) -> Option<StoredPokemon> {
+    let personality = read_u32(data, 0);
+    let ot_id = read_u32(data, 4);
+    let flags = data[19];
+    let marked_bad_egg = flags & 0b001 != 0;
+    let has_species = flags & 0b010 != 0;
+    if !has_species && !marked_bad_egg {
+        return None;
+    }
+
+    let key = personality ^ ot_id;
+    let decrypted: Vec<u32> = (0..12).map(|i| read_u32(data, 32 + i * 4) ^ key).collect();
+    // The game turns Pokemon whose data doesn't match the checksum into bad eggs.
+    let checksum = decrypted.iter().fold(0u16, |sum, &v| {
+        sum.wrapping_add(v as u16).wrapping_add((v >> 16) as u16)
+    });
+    if marked_bad_egg || checksum != u16::from_le_bytes([data[28], data[29]]) {
+        return Some(StoredPokemon::bad_egg(data, location));
+    }
+
+    let positions = SUBSTRUCTURE_POSITIONS[(personality % 24) as usize];
+    let species_and_item = decrypted[positions[GROWTH] * 3];
+    let friendship = (decrypted[positions[GROWTH] * 3 + 2] >> 8) as u8;
+    let ivs = decrypted[positions[MISC] * 3 + 1];
+
+    let Some(species) = national_species(species_and_item as u16) else {
+        return Some(StoredPokemon::bad_egg(data, location));
+    };
+
+    Some(StoredPokemon {
+        location,
+        species,
+        personality,
+        ot_id,
+        nickname: decode_text(&data[8..18]),
+        ot_name: decode_text(&data[20..27]),
+        held_item: (species_and_item >> 16) as u16,
+        level,
+        friendship,
+        is_egg: ivs & (1 << 30) != 0,
+        is_bad_egg: false,
+    })
+}
+
+/// Decodes a string in the character set of the Western Gen 3 games.
+/// Characters the calculator font can't display are replaced with '?'.
+pub fn decode_text(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|&&b| b != 0xFF)
+        .map(|&b| match b {
+            0x00 => ' ',
+            0xA1..=0xAA => (b'0' + (b - 0xA1)) as char,
+            0xAB => '!',
+            0xAC => '?',
+            0xAD => '.',
+            0xAE => '-',
+            0xB3 | 0xB4 => '\'',
+            0xB8 => ',',
+            0xBA => '/',
+            0xBB..=0xD4 => (b'A' + (b - 0xBB)) as char,
+            0xD5..=0xEE => (b'a' + (b - 0xD5)) as char,
+            _ => '?',
+        })
+        .collect()
+}
+
+pub fn read_u32(buf: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// Returns a Pokemon with the given species and otherwise made up values.
+    pub fn pokemon(species: u16) -> StoredPokemon {
+        StoredPokemon {
+            location: Location::Party(0),
+            species,
+            personality: 0x1234_5678,
+            ot_id: 0x0001_D431,
+            nickname: String::from("PIKA"),
+            ot_name: String::from("Ash"),
+            held_item: 0,
+            level: None,
+            friendship: 70,
+            is_egg: false,
+            is_bad_egg: false,
+        }
+    }
+
+    /// Returns empty blocks of the given game.
+    pub fn blank_blocks(version: GameVersion) -> SaveBlocks {
+        let mut trainer = vec![0; SECTOR_DATA_SIZE];
+        let game_code: u32 = match version {
+            GameVersion::RubySapphire => 0,
+            GameVersion::FireRedLeafGreen => 1,
+            GameVersion::Emerald => 0x5EC0_12E7,
+        };
+        trainer[OFFSET_GAME_CODE..OFFSET_GAME_CODE + 4].copy_from_slice(&game_code.to_le_bytes());
+        SaveBlocks {
+            version,
+            trainer,
+            state: vec![0; 4 * SECTOR_DATA_SIZE],
+            storage: vec![0; 9 * SECTOR_DATA_SIZE],
+        }
+    }
+
+    /// Returns a save file whose current slot holds the blocks. The previous slot holds an
+    /// empty save with a lower save index, and the sectors of both slots are rotated.
+    pub fn save_file(blocks: &SaveBlocks, save_index: u32) -> Vec<u8> {
+        let mut buf = vec![0; SAVE_SIZE];
+        let previous = blank_blocks(blocks.version);
+        write_slot(&mut buf, 0, &previous, save_index - 1);
+        write_slot(&mut buf, 1, blocks, save_index);
+        buf
+    }
+
+    fn write_slot(buf: &mut [u8], slot: usize, blocks: &SaveBlocks, save_index: u32) {
+        for id in 0..SLOT_SECTORS {
+            let (block, first) = match id {
+                0 => (&blocks.trainer, 0),
+                1..=4 => (&blocks.state, 1),
+                _ => (&blocks.storage, 5),
+            };
+            let data = &block[(id - first) * SECTOR_DATA_SIZE..(id - first + 1) * SECTOR_DATA_SIZE];
+            let position = slot * SLOT_SECTORS + (id + 3 + slot * 5) % SLOT_SECTORS;
+            let sector = &mut buf[position * SECTOR_SIZE..(position + 1) * SECTOR_SIZE];
+            sector[..SECTOR_DATA_SIZE].copy_from_slice(data);
+            let checksum = sector_checksum(&data[..blocks.version.sector_size(id)]);
+            sector[0xFF4..0xFF6].copy_from_slice(&(id as u16).to_le_bytes());
+            sector[0xFF6..0xFF8].copy_from_slice(&checksum.to_le_bytes());
+            sector[0xFF8..0xFFC].copy_from_slice(&SECTOR_SIGNATURE.to_le_bytes());
+            sector[0xFFC..0x1000].copy_from_slice(&save_index.to_le_bytes());
+        }
+    }
+
+    /// Encodes a Pokemon in the 80 byte format of the boxes. Only species of the first
+    /// two generations are supported, their index is their National Pokedex number.
+    pub fn encode_pokemon(pokemon: &StoredPokemon) -> Vec<u8> {
+        let mut data = vec![0; BOX_POKEMON_SIZE];
+        data[0..4].copy_from_slice(&pokemon.personality.to_le_bytes());
+        data[4..8].copy_from_slice(&pokemon.ot_id.to_le_bytes());
+        data[8..18].copy_from_slice(&encode_text(&pokemon.nickname, 10));
+        data[18] = 2;
+        data[19] = if pokemon.is_egg { 0b110 } else { 0b010 };
+        data[20..27].copy_from_slice(&encode_text(&pokemon.ot_name, 7));
+
+        let positions = SUBSTRUCTURE_POSITIONS[(pokemon.personality % 24) as usize];
+        let mut words = [0u32; 12];
+        words[positions[GROWTH] * 3] = pokemon.species as u32 | ((pokemon.held_item as u32) << 16);
+        words[positions[GROWTH] * 3 + 2] = (pokemon.friendship as u32) << 8;
+        words[positions[MISC] * 3 + 1] = (pokemon.is_egg as u32) << 30;
+        let checksum = words.iter().fold(0u16, |sum, &v| {
+            sum.wrapping_add(v as u16).wrapping_add((v >> 16) as u16)
+        });
+        data[28..30].copy_from_slice(&checksum.to_le_bytes());
+        let key = pokemon.personality ^ pokemon.ot_id;
+        for (i, word) in words.iter().enumerate() {
+            data[32 + i * 4..36 + i * 4].copy_from_slice(&(word ^ key).to_le_bytes());
+        }
+        data
+    }
+
+    /// Adds a Pokemon to the end of the party.
+    pub fn add_to_party(blocks: &mut SaveBlocks, pokemon: &StoredPokemon, level: u8) {
+        let party_offset = blocks.version.party_offset();
+        let count = read_u32(&blocks.state, party_offset) as usize;
+        let start = party_offset + 4 + count * PARTY_POKEMON_SIZE;
+        blocks.state[start..start + BOX_POKEMON_SIZE].copy_from_slice(&encode_pokemon(pokemon));
+        blocks.state[start + OFFSET_LEVEL] = level;
+        blocks.state[party_offset..party_offset + 4]
+            .copy_from_slice(&(count as u32 + 1).to_le_bytes());
+    }
+
+    /// Puts a Pokemon into a slot of a PC box.
+    pub fn put_in_box(blocks: &mut SaveBlocks, b: usize, slot: usize, pokemon: &StoredPokemon) {
+        let start = OFFSET_BOXES + (b * BOX_SIZE + slot) * BOX_POKEMON_SIZE;
+        blocks.storage[start..start + BOX_POKEMON_SIZE].copy_from_slice(&encode_pokemon(pokemon));
+    }
+
+    /// Encodes upper case letters, lower case letters and spaces, padded with terminators.
+    fn encode_text(text: &str, len: usize) -> Vec<u8> {
+        let mut bytes: Vec<u8> = text
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' => 0xBB + (b - b'A'),
+                b'a'..=b'z' => 0xD5 + (b - b'a'),
+                _ => 0x00,
+            })
+            .collect();
+        bytes.resize(len, 0xFF);
+        bytes
+    }
+
+    #[test]
+    fn splits_the_blocks_into_sectors() {
+        for version in [
+            GameVersion::RubySapphire,
+            GameVersion::FireRedLeafGreen,
+            GameVersion::Emerald,
+        ] {
+            let (trainer, state) = version.block_sizes();
+            assert_eq!(version.sector_size(0), trainer);
+            let sizes = |ids: core::ops::Range<usize>| {
+                ids.map(|id| version.sector_size(id)).collect::<Vec<_>>()
+            };
+            assert_eq!(sizes(1..5).iter().sum::<usize>(), state);
+            assert_eq!(sizes(1..4), [SECTOR_DATA_SIZE; 3]);
+            assert_eq!(sizes(5..13), [SECTOR_DATA_SIZE; 8]);
+            assert_eq!(version.sector_size(13), 0x7D0);
+        }
+        assert_eq!(
+            GameVersion::Emerald.sector_size(4),
+            0x3D88 - 3 * SECTOR_DATA_SIZE
+        );
+    }
+
+    #[test]
+    fn folds_the_checksum_to_16_bits() {
+        assert_eq!(sector_checksum(&[0; 8]), 0);
+        assert_eq!(sector_checksum(&[2, 0, 1, 0, 4, 0, 3, 0]), 10);
+        // The sum of the words wraps around before it is folded.
+        assert_eq!(sector_checksum(&[0xFF, 0xFF, 0xFF, 0xFF, 2, 0, 0, 0]), 1);
+        // The carry of the fold is dropped.
+        assert_eq!(sector_checksum(&[0xFF, 0xFF, 0xFF, 0x7F]), 0x7FFE);
+    }
+
+    #[test]
+    fn reads_the_current_slot() {
+        let mut blocks = blank_blocks(GameVersion::Emerald);
+        blocks.state[0x1234] = 0x56;
+        let buf = save_file(&blocks, 7);
+        assert_eq!(current_save_index(&buf), Some(7));
+        assert!(invalid_sectors(&buf).is_empty());
+        let parsed = parse_save_blocks(&buf).unwrap();
+        assert_eq!(parsed.version, GameVersion::Emerald);
+        assert_eq!(parsed.state[0x1234], 0x56);
+        assert!(parse_save_blocks(&buf[..SAVE_SIZE - 1]).is_none());
+    }
+
+    #[test]
+    fn tells_the_games_apart() {
+        for version in [GameVersion::RubySapphire, GameVersion::FireRedLeafGreen] {
+            let buf = save_file(&blank_blocks(version), 1);
+            assert_eq!(parse_save_blocks(&buf).unwrap().version, version);
+        }
+    }
+
+    #[test]
+    fn only_checks_the_data_of_a_sector() {
+        let blocks = blank_blocks(GameVersion::FireRedLeafGreen);
+        let mut buf = save_file(&blocks, 3);
+        let offsets = current_sector_offsets(&buf).unwrap();
+        // The unused end of a sector isn't covered by the checksum.
+        buf[offsets[13] + 0x7D0] = 1;
+        buf[offsets[0] + 0xF24] = 1;
+        assert!(invalid_sectors(&buf).is_empty());
+        buf[offsets[4] + 0x10] = 1;
+        buf[offsets[13] + 0x7CF] = 1;
+        assert_eq!(invalid_sectors(&buf), [4, 13]);
+    }
+
+    #[test]
+    fn writes_the_state_with_its_checksums() {
+        let blocks = blank_blocks(GameVersion::RubySapphire);
+        let mut buf = save_file(&blocks, 1);
+        // Across the border of sectors 1 and 2.
+        let offset = SECTOR_DATA_SIZE - 2;
+        write_state(&mut buf, offset, &[1, 2, 3, 4]).unwrap();
+        assert!(invalid_sectors(&buf).is_empty());
+        assert_eq!(
+            parse_save_blocks(&buf).unwrap().state[offset..offset + 4],
+            [1, 2, 3, 4]
+        );
+
+        // The end of the game state.
+        let (_, state) = GameVersion::RubySapphire.block_sizes();
+        assert!(write_state(&mut buf, state - 2, &[1, 2]).is_some());
+        assert!(write_state(&mut buf, state - 1, &[1, 2]).is_none());
+        assert!(write_state(&mut buf, 4 * SECTOR_DATA_SIZE, &[1]).is_none());
+    }
+
+    #[test]
+    fn refuses_to_write_into_broken_sectors() {
+        let mut buf = save_file(&blank_blocks(GameVersion::Emerald), 1);
+        let offsets = current_sector_offsets(&buf).unwrap();
+        buf[offsets[2] + 5] = 1;
+        assert!(write_state(&mut buf, SECTOR_DATA_SIZE + 8, &[1]).is_none());
+        assert!(write_state(&mut buf, 8, &[1]).is_some());
+    }
+
+    #[test]
+    fn decodes_every_substructure_order() {
+        for order in 0..24 {
+            let mut expected = pokemon(25);
+            expected.personality = 0x1234_5600 + order;
+            expected.held_item = 139;
+            let data = encode_pokemon(&expected);
+            let decoded = decode_pokemon(&data, Location::Box(1, 2), None).unwrap();
+            assert!(decoded.is_same(&expected), "order {}", order);
+            assert_eq!(decoded.location, Location::Box(1, 2));
+            assert_eq!(decoded.friendship, 70);
+            assert!(!decoded.is_bad_egg);
+        }
+    }
+
+    #[test]
+    fn decodes_eggs() {
+        let mut egg = pokemon(172);
+        egg.is_egg = true;
+        egg.friendship = 10;
+        let decoded = decode_pokemon(&encode_pokemon(&egg), Location::Party(0), None).unwrap();
+        assert!(decoded.is_egg);
+        assert_eq!(decoded.friendship, 10);
+    }
+
+    #[test]
+    fn skips_empty_slots() {
+        let data = [0; BOX_POKEMON_SIZE];
+        assert!(decode_pokemon(&data, Location::Box(0, 0), None).is_none());
+    }
+
+    #[test]
+    fn turns_broken_data_into_bad_eggs() {
+        let mut data = encode_pokemon(&pokemon(25));
+        data[40] ^= 1;
+        let decoded = decode_pokemon(&data, Location::Party(3), None).unwrap();
+        assert!(decoded.is_bad_egg && decoded.is_egg);
+        assert_eq!(decoded.location, Location::Party(3));
+
+        let mut data = encode_pokemon(&pokemon(25));
+        data[19] |= 0b001;
+        assert!(
+            decode_pokemon(&data, Location::Party(0), None)
+                .unwrap()
+                .is_bad_egg
+        );
+    }
+
+    #[test]
+    fn reads_the_party_and_the_boxes() {
+        let mut blocks = blank_blocks(GameVersion::Emerald);
+        add_to_party(&mut blocks, &pokemon(25), 12);
+        add_to_party(&mut blocks, &pokemon(133), 30);
+        put_in_box(&mut blocks, 2, 5, &pokemon(1));
+
+        let party = party_pokemon(&blocks);
+        assert_eq!(party.len(), 2);
+        assert_eq!(party[1].species, 133);
+        assert_eq!(party[1].level, Some(30));
+        assert_eq!(party[1].location, Location::Party(1));
+
+        let all = stored_pokemon(&blocks);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[2].species, 1);
+        assert_eq!(all[2].location, Location::Box(2, 5));
+        assert_eq!(all[2].level, None);
+    }
+
+    #[test]
+    fn keeps_broken_party_slots() {
+        let mut blocks = blank_blocks(GameVersion::FireRedLeafGreen);
+        add_to_party(&mut blocks, &pokemon(25), 5);
+        let start = blocks.version.party_offset() + 4 + 40;
+        blocks.state[start] ^= 0xFF;
+        let party = party_pokemon(&blocks);
+        assert_eq!(party.len(), 1);
+        assert!(party[0].is_bad_egg);
+    }
+
+    #[test]
+    fn tells_shinies_apart() {
+        let mut shiny = pokemon(25);
+        shiny.ot_id = 0;
+        shiny.personality = 0x0005_0002;
+        assert!(shiny.is_shiny());
+        shiny.personality = 0x0008_0000;
+        assert!(!shiny.is_shiny());
+    }
+
+    #[test]
+    fn decodes_text() {
+        assert_eq!(decode_text(&[0xC2, 0xBB, 0xDA, 0xFF, 0xBB]), "HAf");
+        assert_eq!(decode_text(&[0xA1, 0xAB, 0x00, 0xB4, 0x01]), "0! '?");
+    }
+}
@@ -0,0 +1,235 @@
+use crate::prelude::*;
+
+/// The number of changed byte ranges that are listed per sector.
+const MAX_RANGES: usize = 8;
+
+/// Compares two save files, e.g. a backup and the save written by a trade, and describes
+/// what changed: the trainer, the Pokedex, the party and box slots and the raw sectors.
+pub fn diff_saves(old: &[u8], new: &[u8]) -> Result<Vec<String>> {
+    let old_save = parse_save(old, "old")?;
+    let new_save = parse_save(new, "new")?;
+    let (old_blocks, new_blocks) = (&old_save.blocks, &new_save.blocks);
+    let old_card = trainer_card(old_blocks, trainer_name(&old_save.save));
+    let new_card = trainer_card(new_blocks, trainer_name(&new_save.save));
+
+    let mut lines = Vec::new();
+    section(&mut lines, "Trainer", diff_trainer(&old_card, &new_card));
+    section(&mut lines, "Pokedex", diff_pokedex(old_blocks, new_blocks));
+    section(&mut lines, "Pokemon", diff_pokemon(old_blocks, new_blocks));
+    section(&mut lines, "Sectors", diff_sectors(old, new));
+    Ok(lines)
+}
+
+/// Appends a section with a title and its indented entries.
+fn section(lines: &mut Vec<String>, title: &str, entries: Vec<String>) {
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines.push(String::from(title));
+    if entries.is_empty() {
+        lines.push(String::from("  (no changes)"));
+    }
+    lines.extend(entries.into_iter().map(|entry| format!("  {}", entry)));
+}
+
+fn diff_trainer(old: &TrainerCard, new: &TrainerCard) -> Vec<String> {
+    trainer_fields(old)
+        .into_iter()
+        .zip(trainer_fields(new))
+        .filter(|((_, a), (_, b))| a != b)
+        .map(|((label, a), (_, b))| format!("{}: {} -> {}", label, a, b))
+        .collect()
+}
+
+fn trainer_fields(card: &TrainerCard) -> Vec<(&'static str, String)> {
+    let badges: Vec<String> = (0..8)
+        .filter(|&i| card.badges[i])
+        .map(|i| (i + 1).to_string())
+        .collect();
+    let (hours, minutes, seconds) = card.play_time;
+    vec![
+        ("Game", String::from(card.version.name())),
+        ("Name", card.name.clone()),
+        (
+            "Gender",
+            String::from(if card.female { "Girl" } else { "Boy" }),
+        ),
+        ("ID", format!("{:05}", card.public_id)),
+        ("Secret ID", format!("{:05}", card.secret_id)),
+        (
+            "Play time",
+            format!("{}:{:02}:{:02}", hours, minutes, seconds),
+        ),
+        ("Money", card.money.to_string()),
+        ("Badges", format!("[{}]", badges.join(" "))),
+        ("Seen", card.dex_seen.to_string()),
+        ("Caught", card.dex_caught.to_string()),
+        (
+            "Hall of Fame",
+            String::from(if card.hall_of_fame { "yes" } else { "no" }),
+        ),
+    ]
+}
+
+fn diff_pokedex(old: &SaveBlocks, new: &SaveBlocks) -> Vec<String> {
+    let (old, new) = (Pokedex::read(old), Pokedex::read(new));
+    let mut entries = Vec::new();
+    for species in 1..=NATIONAL_DEX_SIZE as u16 {
+        let name = species_name(species);
+        for (flag, before, after) in [
+            ("seen", old.is_seen(species), new.is_seen(species)),
+            ("caught", old.is_caught(species), new.is_caught(species)),
+        ] {
+            if before != after {
+                let sign = if after { '+' } else { '-' };
+                entries.push(format!("{} {} #{:03} {}", sign, flag, species, name));
+            }
+        }
+    }
+    entries
+}
+
+fn diff_pokemon(old: &SaveBlocks, new: &SaveBlocks) -> Vec<String> {
+    let (old, new) = (stored_pokemon(old), stored_pokemon(new));
+    let mut entries = Vec::new();
+    if old.len() != new.len() {
+        entries.push(format!("Count: {} -> {}", old.len(), new.len()));
+    }
+
+    let mut locations: Vec<Location> = old.iter().chain(&new).map(|p| p.location).collect();
+    locations.sort_by_key(|&location| match location {
+        Location::Party(slot) => (0, 0, slot),
+        Location::Box(b, slot) => (1, b, slot),
+    });
+    locations.dedup();
+
+    for location in locations {
+        let before = old.iter().find(|p| p.location == location);
+        let after = new.iter().find(|p| p.location == location);
+        if let (Some(a), Some(b)) = (before, after) {
+            if a.is_same(b) {
+                continue;
+            }
+        }
+        entries.push(format!(
+            "{}: {} -> {}",
+            slot_name(location),
+            before.map_or(String::from("(empty)"), describe),
+            after.map_or(String::from("(empty)"), describe)
+        ));
+    }
+    entries
+}
+
+fn slot_name(location: Location) -> String {
+    match location {
+        Location::Party(slot) => format!("Party {}", slot + 1),
+        Location::Box(b, slot) => format!("Box {} slot {}", b + 1, slot + 1),
+    }
+}
+
+/// Describes a Pokemon well enough to tell it apart from others of its species.
+fn describe(pokemon: &StoredPokemon) -> String {
+    let level = pokemon
+        .level
+        .map_or(String::new(), |level| format!(" Lv {}", level));
+    let item = item_name(pokemon.held_item).map_or(String::new(), |item| format!(" @{}", item));
+    format!(
+        "{} \"{}\"{}{} (OT {} {:05}, PID {:08X})",
+        pokemon.label(),
+        pokemon.nickname,
+        level,
+        item,
+        pokemon.ot_name,
+        pokemon.ot_id as u16,
+        pokemon.personality
+    )
+}
+
+/// Lists where the sectors of the current saves are in the files and which of their bytes changed.
+fn diff_sectors(old: &[u8], new: &[u8]) -> Vec<String> {
+    let (Some(old_offsets), Some(new_offsets)) =
+        (current_sector_offsets(old), current_sector_offsets(new))
+    else {
+        return vec![String::from("A sector of one of the saves is missing.")];
+    };
+    let mut entries = vec![format!(
+        "Save index: {} -> {}",
+        current_save_index(old).map_or(String::from("?"), |i| i.to_string()),
+        current_save_index(new).map_or(String::from("?"), |i| i.to_string())
+    )];
+
+    for (id, (&old_offset, &new_offset)) in old_offsets.iter().zip(&new_offsets).enumerate() {
+        let old_sector = &old[old_offset..old_offset + SECTOR_SIZE];
+        let new_sector = &new[new_offset..new_offset + SECTOR_SIZE];
+        let checksum = |sector: &[u8]| u16::from_le_bytes([sector[0xFF6], sector[0xFF7]]);
+        let ranges = changed_ranges(
+            &old_sector[..SECTOR_DATA_SIZE],
+            &new_sector[..SECTOR_DATA_SIZE],
+        );
+        if ranges.is_empty() && checksum(old_sector) == checksum(new_sector) {
+            continue;
+        }
+
+        // Offsets are given within the block, as used by `SaveBlocks`.
+        let (block, base) = match id {
+            0 => ("trainer", 0),
+            1..=4 => ("state", (id - 1) * SECTOR_DATA_SIZE),
+            _ => ("storage", (id - 5) * SECTOR_DATA_SIZE),
+        };
+        entries.push(format!(
+            "Sector {} ({}): file 0x{:05X} -> 0x{:05X}, checksum {:04X} -> {:04X}",
+            id,
+            block,
+            old_offset,
+            new_offset,
+            checksum(old_sector),
+            checksum(new_sector)
+        ));
+        for &(start, end) in ranges.iter().take(MAX_RANGES) {
+            entries.push(format!(
+                "  {} 0x{:04X}-0x{:04X} ({} bytes)",
+                block,
+                base + start,
+                base + end - 1,
+                end - start
+            ));
+        }
+        if ranges.len() > MAX_RANGES {
+            entries.push(format!("  ... {} more", ranges.len() - MAX_RANGES));
+        }
+    }
+    entries
+}
+
+/// Returns the ranges of bytes that differ, as start and exclusive end.
+fn changed_ranges(old: &[u8], new: &[u8]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for i in (0..old.len().min(new.len())).filter(|&i| old[i] != new[i]) {
+        match ranges.last_mut() {
+            Some((_, end)) if *end == i => *end = i + 1,
+            _ => ranges.push((i, i + 1)),
+        }
+    }
+    ranges
+}
+
+/// Prints what changed between two save files and exits. Used by `--diff`.
+pub fn exit_with_diff(old: &str, new: &str) -> ! {
+    let result = read_file(old)
+        .and_then(|old| Ok((old, read_file(new)?)))
+        .and_then(|(old, new)| diff_saves(&old, &new));
+    match result {
+        Ok(lines) => {
+            println!("{} -> {}\n", old, new);
+            for line in lines {
+                println!("{}", line);
+            }
+            std::process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
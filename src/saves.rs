@@ -1,63 +1,303 @@
 use crate::prelude::*;
-pub const EXT_SAVEFILE: &str = {
-    #[cfg(feature = "calculator-build")]
-    {
-        ".sav.tns"
+/// The extensions that save files are recognized by, unless configured otherwise.
+/// On the calculator, every file additionally ends with `.tns`.
+pub const DEFAULT_SAVE_EXTENSIONS: [&str; 5] = [".sav", ".sav.tns", ".srm", ".sa1", ".dsv"];
+
+/// The size of a Gen 3 save: the 128 KiB flash chip of the cartridge.
+pub const SAVE_SIZE: usize = 0x20000;
+/// How many bytes emulators may append to a save (e.g. a real-time clock or a footer).
+const MAX_TRAILER_SIZE: usize = 0x400;
+/// The size of a sector of the save. Every sector ends with a footer.
+pub const SECTOR_SIZE: usize = 0x1000;
+/// A save contains two slots of 14 sectors, the previous save and the current one.
+pub const SECTORS: usize = 28;
+/// The signature in the footer of every sector that was written by the game.
+pub const SECTOR_SIGNATURE: u32 = 0x08012025;
+
+/// How many levels of subdirectories the recursive scan descends into.
+const MAX_SCAN_DEPTH: usize = 4;
+
+/// Finds the save files in the save directory, and in its subdirectories if enabled.
+/// Returns the names of the saves: their paths relative to the save directory.
+pub fn find_saves() -> Result<Vec<String>> {
+    let dir = save_dir();
+    let depth = if recursive_scan() { MAX_SCAN_DEPTH } else { 0 };
+    let mut found = Vec::new();
+    scan_dir(&dir, depth, &mut found)?;
+    Ok(found.iter().map(|path| save_name(&dir, path)).collect())
+}
+
+/// Collects the save files in `dir`, then descends into its subdirectories.
+fn scan_dir(dir: &str, depth: usize, found: &mut Vec<String>) -> Result<()> {
+    let entries = read_dir(dir)?;
+    found.extend(
+        entries
+            .iter()
+            .filter(|f| has_save_extension(f) && is_gen3_save(f))
+            .cloned(),
+    );
+    if depth == 0 {
+        return Ok(());
     }
-    #[cfg(feature = "desktop")]
+    for entry in entries
+        .iter()
+        .filter(|e| !file_name(e).starts_with('.') && is_dir(e))
     {
-        ".sav"
+        // An unreadable subdirectory shouldn't hide the saves in the other ones.
+        let _ = scan_dir(entry, depth - 1, found);
     }
-};
+    Ok(())
+}
 
-pub fn find_first_two_sav_tns(files: &[String]) -> Result<(String, String)> {
-    let mut matches = files.iter().filter(|f| f.ends_with(EXT_SAVEFILE)).take(2);
+/// Returns the name of the save at the given path, relative to the save directory.
+fn save_name(dir: &str, path: &str) -> String {
+    path.strip_prefix(dir)
+        .map(|p| p.trim_start_matches(['/', '\\']))
+        .unwrap_or(path)
+        .to_string()
+}
 
-    let first_full_path = matches.next().ok_or_else(|| AppError::MissingFiles)?;
-    let second_full_path = matches.next().ok_or_else(|| AppError::MissingFiles)?;
+/// Returns true if the file has one of the configured save extensions.
+/// A trailing `.tns` is ignored, so saves work the same on the calculator and the desktop.
+fn has_save_extension(path: &str) -> bool {
+    let path = path.to_lowercase();
+    let path = path.strip_suffix(".tns").unwrap_or(&path);
+    settings().save_extensions.iter().any(|ext| {
+        let ext = ext.strip_suffix(".tns").unwrap_or(ext);
+        path.ends_with(ext)
+    })
+}
 
-    // Use the abstracted function from platform::fs
-    let first_stripped = get_file_basename(first_full_path);
-    let second_stripped = get_file_basename(second_full_path);
+/// Returns the configured save extensions for messages, e.g. ".sav, .srm".
+pub fn save_extension_list() -> String {
+    settings().save_extensions.join(", ")
+}
 
-    Ok((first_stripped, second_stripped))
+/// Checks the size and the sector footers of a file to find out whether it is a Gen 3 save.
+fn is_gen3_save(path: &str) -> bool {
+    let Ok(size) = file_size(path) else {
+        return false;
+    };
+    let size = size as usize;
+    if size < SAVE_SIZE || size > SAVE_SIZE + MAX_TRAILER_SIZE {
+        return false;
+    }
+    // Only the sector footers (section id, checksum, signature, save counter) are read.
+    let footers: Vec<(u64, usize)> = (0..SECTORS)
+        .map(|sector| ((sector * SECTOR_SIZE + 0xFF4) as u64, 12))
+        .collect();
+    let Ok(buf) = read_file_ranges(path, &footers) else {
+        return false;
+    };
+    let is_valid = |footer: &[u8]| {
+        let id = u16::from_le_bytes([footer[0], footer[1]]);
+        let signature = u32::from_le_bytes([footer[4], footer[5], footer[6], footer[7]]);
+        (id as usize) < SLOT_SECTORS && signature == SECTOR_SIGNATURE
+    };
+    // A save that was only saved once has written just one of its two slots,
+    // so the majority is required per slot.
+    buf.chunks_exact(12 * SLOT_SECTORS).any(|slot| {
+        slot.chunks_exact(12)
+            .filter(|footer| is_valid(footer))
+            .count()
+            > SLOT_SECTORS / 2
+    })
 }
 
-use pkmn_savedata::{core_types::PokemonSpecies, gba::GbaSave};
+/// Returns the names of the saves to trade between: the saves of the last trade if they
+/// are still there, otherwise the first two saves that were found.
+pub fn find_trade_saves() -> Result<(String, String)> {
+    let found = find_saves()?;
+    if let Some((save1, save2)) = &settings().last_saves {
+        if found.contains(save1) && found.contains(save2) {
+            return Ok((save1.clone(), save2.clone()));
+        }
+    }
+    let mut saves = found.into_iter();
+
+    let first = saves.next().ok_or_else(|| AppError::MissingFiles)?;
+    let second = saves.next().ok_or_else(|| AppError::MissingFiles)?;
+
+    Ok((first, second))
+}
+
+use pkmn_savedata::{
+    core_types::PokemonSpecies,
+    gba::{GbaSave, LanguageGBA},
+};
+
+/// The maximum length of a Pokemon nickname in Gen 3.
+pub const MAX_NICKNAME_LENGTH: usize = 10;
+
+/// The characters of the Gen 3 character set that can be typed into a nickname.
+/// Symbols that the calculator font can't display (e.g. ♂ and ♀) are left out.
+pub const NICKNAME_CHARSET: [&str; 6] = [
+    "ABCDEFGHIJKLM",
+    "NOPQRSTUVWXYZ",
+    "abcdefghijklm",
+    "nopqrstuvwxyz",
+    "0123456789!?.",
+    "-,/' ",
+];
+
+/// Returns true if the character can be part of a nickname.
+pub fn is_nickname_char(ch: char) -> bool {
+    NICKNAME_CHARSET.iter().any(|row| row.contains(ch))
+}
 
 pub fn save_file_path(name: &str) -> String {
-    // Construct filename with extension
-    let filename_with_ext = format!("{}{}", name, EXT_SAVEFILE);
     // Use the abstracted function from platform::fs
-    path_join(&get_dir(), &filename_with_ext)
+    path_join(&save_dir(), name)
 }
 
 pub fn trainer_name(save: &GbaSave) -> String {
     save.game_state().trainer_name().decode(true)
 }
 
-pub fn party_names(save: &GbaSave) -> Result<Vec<String>> {
-    let game_state = save.game_state();
-    let mut party_names = Vec::new();
-    for pkm in game_state.party_iter() {
-        let species_national = pkm.decode().species().ok_or(AppError::Custom(
-            "Pokemon has unknown species (code 1).".to_string(),
-        ))?;
-        let species = PokemonSpecies::try_from(species_national)
-            .map_err(|_| AppError::Custom("Pokemon has unknown species (code 2).".to_string()))?;
+/// A save file as it was read: the save parsed by `pkmn_savedata` and the blocks of its
+/// current save, split off the same bytes.
+pub struct LoadedSave {
+    pub save: GbaSave,
+    pub blocks: SaveBlocks,
+}
+
+/// Reads the save file with the given name.
+pub fn load_save(name: &str) -> Result<LoadedSave> {
+    let buf =
+        read_file(&save_file_path(name)).with_context(|| trf(Text::ReadSaveFailed, &[name]))?;
+    parse_save(&buf, name)
+}
 
-        let pokemon_name = species.to_string();
-        party_names.push(pokemon_name);
+/// Parses the contents of the save file with the given name.
+pub fn parse_save(buf: &[u8], name: &str) -> Result<LoadedSave> {
+    // Data that emulators append after the save isn't part of it.
+    let buf = &buf[..buf.len().min(SAVE_SIZE)];
+    let save = GbaSave::from_bytes(buf, LanguageGBA::English)
+        .map_err(|_| AppError::ParseFailed(name.to_string()))?;
+    let blocks =
+        parse_save_blocks(buf).ok_or_else(|| AppError::InvalidSectors(name.to_string()))?;
+    Ok(LoadedSave { save, blocks })
+}
+
+pub fn backup_file_path(name: &str) -> String {
+    // Keep `.tns` as the last extension, otherwise the calculator doesn't show the file.
+    let backup = match name.strip_suffix(".tns") {
+        Some(stem) => format!("{}.bak.tns", stem),
+        None => format!("{}.bak", name),
+    };
+    path_join(&save_dir(), &backup)
+}
+
+pub fn write_save(name: &str, buf: &[u8]) -> Result<()> {
+    let path = save_file_path(name);
+    let backup_path = backup_file_path(name);
+    let original = read_file(&path).with_context(|| trf(Text::ReadBeforeWriteFailed, &[name]))?;
+    let backup = match settings().backup_policy {
+        BackupPolicy::Off => false,
+        BackupPolicy::Original => !file_exists(&backup_path),
+        BackupPolicy::EveryWrite => true,
+    };
+    if backup {
+        write_file(&backup_path, &original).with_context(|| trf(Text::BackupFailed, &[name]))?;
+    }
+
+    // Keep the data that emulators append after the save, so the file stays in their format.
+    let mut data = buf.to_vec();
+    if buf.len() == SAVE_SIZE && original.len() > SAVE_SIZE {
+        data.extend_from_slice(&original[SAVE_SIZE..]);
+    }
+    write_file(&path, &data).map_err(|e| AppError::WriteFailed {
+        save: name.to_string(),
+        written: Vec::new(),
+        source: Box::new(e),
+    })
+}
+
+/// Writes the backups of the given saves back over the save files.
+///
+/// Every backup is read before the first save is written, so a backup that can't be read
+/// leaves all of the saves as they are.
+pub fn restore_backups(names: &[String]) -> Result<()> {
+    let backups = names
+        .iter()
+        .map(|name| {
+            read_file(&backup_file_path(name)).with_context(|| trf(Text::ReadBackupFailed, &[name]))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for (name, backup) in names.iter().zip(backups) {
+        write_file(&save_file_path(name), &backup)
+            .with_context(|| trf(Text::RestoreFailed, &[name]))?;
+    }
+    Ok(())
+}
+
+/// Returns the nickname of the party Pokemon at the given index.
+pub fn nickname(save: &GbaSave, index: usize) -> Result<String> {
+    let pkm = save
+        .game_state()
+        .party_iter()
+        .nth(index)
+        .ok_or(AppError::PokemonNotFound)?;
+    Ok(pkm.decode().nickname().decode(true))
+}
+
+/// Renames the party Pokemon at the given index.
+pub fn set_nickname(save: &mut GbaSave, index: usize, nickname: &str) -> Result<()> {
+    if nickname.is_empty()
+        || nickname.chars().count() > MAX_NICKNAME_LENGTH
+        || !nickname.chars().all(is_nickname_char)
+    {
+        return Err(AppError::InvalidNickname(nickname.to_string()));
     }
-    Ok(party_names)
+
+    let pkm = save
+        .game_state_mut()
+        .party_iter_mut()
+        .nth(index)
+        .ok_or(AppError::PokemonNotFound)?;
+    let mut decoded = pkm.decode();
+    decoded.set_nickname(nickname)?;
+    *pkm = decoded.encode();
+    Ok(())
 }
 
+/// Returns the party of a loaded save, including eggs and bad eggs.
+///
+/// `pkmn_savedata` can't tell eggs apart and fails on bad eggs,
+/// so the party is decoded from the blocks of the save instead.
+pub fn party_of(loaded: &LoadedSave) -> Vec<StoredPokemon> {
+    party_pokemon(&loaded.blocks)
+}
+
+/// Trades the Pokemon at the given party indices and returns the traded saves.
+/// The saves are consumed, their blocks would no longer match them.
 pub fn trade_pokemon(
-    save1: &mut GbaSave,
-    save2: &mut GbaSave,
+    loaded1: LoadedSave,
+    loaded2: LoadedSave,
     pokemon1: usize,
     pokemon2: usize,
-) -> Result<()> {
+) -> Result<(GbaSave, GbaSave)> {
+    let party1 = party_of(&loaded1);
+    let party2 = party_of(&loaded2);
+    let (mut save1, mut save2) = (loaded1.save, loaded2.save);
+    let traded1 = party1.get(pokemon1).ok_or(AppError::PokemonNotFound)?;
+    let traded2 = party2.get(pokemon2).ok_or(AppError::PokemonNotFound)?;
+    if traded1.is_bad_egg || traded2.is_bad_egg {
+        return Err(AppError::BadEgg);
+    }
+    // Eggs are moved as they are: the friendship value counts the steps until they hatch
+    // and the original trainer stays the one who received the egg. The species is only
+    // registered in the Pokedex once the egg hatches.
+    let (egg1, egg2) = (traded1.is_egg, traded2.is_egg);
+    let unknown_species = |save: &GbaSave, slot: usize| AppError::UnknownSpecies {
+        save: trainer_name(save),
+        slot: slot + 1,
+    };
+    let (unknown1, unknown2) = (
+        unknown_species(&save1, pokemon1),
+        unknown_species(&save2, pokemon2),
+    );
+
     let state1 = save1.game_state_mut();
     let state2 = save2.game_state_mut();
 
@@ -78,23 +318,25 @@ pub fn trade_pokemon(
 
     // Save 1 <-- Pokemon 2
     state1.party_append(&pkm2)?;
-    let species_national2 = pkm2.decode().species().ok_or(AppError::Custom(
-        "Pokemon 2 couln't be added to the Pokedex of 1 (code 1).".to_string(),
-    ))?;
-    let species2 = PokemonSpecies::try_from(species_national2).map_err(|_| {
-        AppError::Custom("Pokemon 2 couln't be added to the Pokedex of 1 (code 2).".to_string())
-    })?;
-    state1.set_pokedex_species(species2);
+    if !egg2 {
+        let species2 = pkm2
+            .decode()
+            .species()
+            .and_then(|species| PokemonSpecies::try_from(species).ok())
+            .ok_or(unknown2)?;
+        state1.set_pokedex_species(species2);
+    }
 
     // Pokemon1 --> Save 2
     state2.party_append(&pkm1)?;
-    let species_national1 = pkm1.decode().species().ok_or(AppError::Custom(
-        "Pokemon 1 couln't be added to the Pokedex of 2 (code 1).".to_string(),
-    ))?;
-    let species1 = PokemonSpecies::try_from(species_national1).map_err(|_| {
-        AppError::Custom("Pokemon 1 couln't be added to the Pokedex of 2 (code 2).".to_string())
-    })?;
-    state2.set_pokedex_species(species1);
+    if !egg1 {
+        let species1 = pkm1
+            .decode()
+            .species()
+            .and_then(|species| PokemonSpecies::try_from(species).ok())
+            .ok_or(unknown1)?;
+        state2.set_pokedex_species(species1);
+    }
 
-    Ok(())
+    Ok((save1, save2))
 }
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+/// The level ranges the search can be narrowed down to.
+pub const LEVEL_RANGES: [(u8, u8); 5] = [(1, 20), (21, 40), (41, 60), (61, 80), (81, 100)];
+
+/// Criteria to find Pokemon in the party and the boxes. Empty criteria match everything.
+#[derive(Clone, Debug, Default)]
+pub struct PokemonFilter {
+    /// Part of the species name or the nickname. Eggs are found by the word for egg
+    /// in the language of the user interface.
+    pub name: String,
+    pub pokemon_type: Option<PokemonType>,
+    /// The lowest and the highest level. Only party Pokemon have a known level, so a level
+    /// range leaves out the boxes.
+    pub level: Option<(u8, u8)>,
+    pub shiny: Option<bool>,
+    /// Part of the name of the held item.
+    pub held_item: String,
+    /// Part of the name of the original trainer.
+    pub ot: String,
+    /// The index of the nature in `NATURES`.
+    pub nature: Option<usize>,
+}
+
+impl PokemonFilter {
+    pub fn matches(&self, pokemon: &StoredPokemon) -> bool {
+        let types = species_types(pokemon.species);
+        let item = item_name(pokemon.held_item).unwrap_or("");
+
+        (contains(&pokemon.label(), &self.name) || contains(&pokemon.nickname, &self.name))
+            && self.pokemon_type.is_none_or(|t| {
+                types.is_some_and(|(first, second)| first == t || second == Some(t))
+            })
+            && self.level.is_none_or(|(min, max)| {
+                pokemon
+                    .level
+                    .is_some_and(|level| (min..=max).contains(&level))
+            })
+            && self.shiny.is_none_or(|shiny| pokemon.is_shiny() == shiny)
+            && contains(item, &self.held_item)
+            && contains(&pokemon.ot_name, &self.ot)
+            && self.nature.is_none_or(|nature| nature == pokemon.nature())
+    }
+}
+
+/// Case-insensitive substring search. An empty pattern is found in every text.
+fn contains(text: &str, pattern: &str) -> bool {
+    text.to_lowercase()
+        .contains(pattern.trim().to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save_data::tests::pokemon;
+
+    fn pikachu() -> StoredPokemon {
+        let mut pikachu = pokemon(25);
+        pikachu.nickname = String::from("Sparky");
+        pikachu.ot_name = String::from("Ash");
+        // King's Rock.
+        pikachu.held_item = 187;
+        pikachu.level = Some(25);
+        pikachu
+    }
+
+    #[test]
+    fn matches_everything_without_criteria() {
+        assert!(PokemonFilter::default().matches(&pikachu()));
+    }
+
+    #[test]
+    fn matches_names_items_and_trainers_in_any_case() {
+        let matches = |filter: PokemonFilter| filter.matches(&pikachu());
+        let name = |name: &str| PokemonFilter {
+            name: String::from(name),
+            ..PokemonFilter::default()
+        };
+        assert!(matches(name("CHU")));
+        assert!(matches(name(" spark ")));
+        assert!(!matches(name("raichu")));
+        assert!(matches(PokemonFilter {
+            held_item: String::from("king"),
+            ot: String::from("ASH"),
+            ..PokemonFilter::default()
+        }));
+        assert!(!matches(PokemonFilter {
+            ot: String::from("Gary"),
+            ..PokemonFilter::default()
+        }));
+    }
+
+    #[test]
+    fn matches_types_natures_and_shininess() {
+        let pikachu = pikachu();
+        let filter = |pokemon_type, nature, shiny| PokemonFilter {
+            pokemon_type,
+            nature,
+            shiny,
+            ..PokemonFilter::default()
+        };
+        let nature = Some(pikachu.nature());
+        let shiny = Some(pikachu.is_shiny());
+        assert!(filter(Some(PokemonType::Electric), nature, shiny).matches(&pikachu));
+        assert!(!filter(Some(PokemonType::Water), None, None).matches(&pikachu));
+        assert!(!filter(None, Some((pikachu.nature() + 1) % 25), None).matches(&pikachu));
+        assert!(!filter(None, None, Some(!pikachu.is_shiny())).matches(&pikachu));
+    }
+
+    #[test]
+    fn leaves_out_unknown_levels_when_filtering_by_level() {
+        let mut pikachu = pikachu();
+        let filter = |level| PokemonFilter {
+            level: Some(level),
+            ..PokemonFilter::default()
+        };
+        assert!(filter(LEVEL_RANGES[1]).matches(&pikachu));
+        assert!(!filter(LEVEL_RANGES[0]).matches(&pikachu));
+        pikachu.level = None;
+        assert!(!filter(LEVEL_RANGES[1]).matches(&pikachu));
+    }
+}
@@ -0,0 +1,445 @@
+use crate::prelude::*;
+
+/// The environment variable that overrides the save directory on desktop.
+#[cfg(not(feature = "calculator-build"))]
+const ENV_SAVE_DIR: &str = "NTRADEADVANCE_DIR";
+
+/// The version of the settings file format that is written.
+///
+/// Files without a version were written before the format was versioned. They only contain
+/// the save directory keys, which haven't changed since, so they are read like version 1.
+/// Files of a newer version are ignored and never overwritten.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// How fast animations play.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+    /// Animations are skipped.
+    Off,
+}
+
+impl AnimationSpeed {
+    pub const ALL: [AnimationSpeed; 4] = [
+        AnimationSpeed::Slow,
+        AnimationSpeed::Normal,
+        AnimationSpeed::Fast,
+        AnimationSpeed::Off,
+    ];
+
+    /// The name stored in the settings file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnimationSpeed::Slow => "Slow",
+            AnimationSpeed::Normal => "Normal",
+            AnimationSpeed::Fast => "Fast",
+            AnimationSpeed::Off => "Off",
+        }
+    }
+
+    /// The name shown in the settings.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            AnimationSpeed::Slow => Text::SpeedSlow,
+            AnimationSpeed::Normal => Text::SpeedNormal,
+            AnimationSpeed::Fast => Text::SpeedFast,
+            AnimationSpeed::Off => Text::SpeedOff,
+        })
+    }
+
+    /// Returns how long a step that takes `ms` milliseconds at normal speed takes.
+    pub fn duration(&self, ms: u32) -> u32 {
+        match self {
+            AnimationSpeed::Slow => ms * 2,
+            AnimationSpeed::Normal => ms,
+            AnimationSpeed::Fast => ms / 2,
+            AnimationSpeed::Off => 0,
+        }
+    }
+}
+
+/// When a copy of a save file is made before it is overwritten.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackupPolicy {
+    /// No backups are made.
+    Off,
+    /// The first write keeps a copy of the original file, later writes leave it alone.
+    #[default]
+    Original,
+    /// Every write replaces the backup with the file as it was before.
+    EveryWrite,
+}
+
+impl BackupPolicy {
+    pub const ALL: [BackupPolicy; 3] = [
+        BackupPolicy::Off,
+        BackupPolicy::Original,
+        BackupPolicy::EveryWrite,
+    ];
+
+    /// The name stored in the settings file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackupPolicy::Off => "Off",
+            BackupPolicy::Original => "Keep original",
+            BackupPolicy::EveryWrite => "Every write",
+        }
+    }
+
+    /// The name shown in the settings.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            BackupPolicy::Off => Text::BackupOff,
+            BackupPolicy::Original => Text::BackupOriginal,
+            BackupPolicy::EveryWrite => Text::BackupEveryWrite,
+        })
+    }
+}
+
+/// User settings that are kept between runs, in the file at `platform::fs::settings_path`.
+///
+/// The file consists of `key=value` lines. Unknown keys and values are ignored, which
+/// keeps files of the current version readable as keys are added. Files of a newer
+/// version are rejected and left unchanged, see `SETTINGS_VERSION`.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The directory that is searched for saves. The platform default is used if unset.
+    pub save_dir: Option<String>,
+    /// Whether saves in subdirectories of the save directory are found as well.
+    pub recursive: bool,
+    /// The color of the text in the menus.
+    pub color: u8,
+    pub animation_speed: AnimationSpeed,
+    pub backup_policy: BackupPolicy,
+    /// The names of the saves of the last trade, they are loaded again next time.
+    pub last_saves: Option<(String, String)>,
+    /// The extensions of the files that are checked for saves, in lower case.
+    pub save_extensions: Vec<String>,
+    /// The language of the user interface. The language of the saves is used if unset.
+    pub language: Option<Language>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            save_dir: None,
+            recursive: false,
+            color: COLOR_WHITE,
+            animation_speed: AnimationSpeed::default(),
+            backup_policy: BackupPolicy::default(),
+            last_saves: None,
+            save_extensions: DEFAULT_SAVE_EXTENSIONS
+                .iter()
+                .map(|ext| ext.to_string())
+                .collect(),
+            language: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Parses the contents of a settings file. Broken lines are skipped.
+    /// Returns None if the file was written in a newer format than `SETTINGS_VERSION`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut settings = Settings::default();
+        let mut version = 0;
+        let mut last_save1 = None;
+        let mut last_save2 = None;
+        for line in text.lines().map(str::trim) {
+            if line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                // A version that can't be read is from a newer format, too.
+                "version" => version = value.parse().unwrap_or(u32::MAX),
+                "save_dir" if !value.is_empty() => settings.save_dir = Some(value.to_string()),
+                "recursive" => settings.recursive = value == "true",
+                "color" => {
+                    if let Some(&(_, color)) = TEXT_COLORS.iter().find(|(name, _)| *name == value) {
+                        settings.color = color;
+                    }
+                }
+                "animation_speed" => {
+                    if let Some(speed) = AnimationSpeed::ALL.iter().find(|s| s.name() == value) {
+                        settings.animation_speed = *speed;
+                    }
+                }
+                "backup_policy" => {
+                    if let Some(policy) = BackupPolicy::ALL.iter().find(|p| p.name() == value) {
+                        settings.backup_policy = *policy;
+                    }
+                }
+                "language" => settings.language = Language::from_code(value),
+                "last_save1" if !value.is_empty() => last_save1 = Some(value.to_string()),
+                "last_save2" if !value.is_empty() => last_save2 = Some(value.to_string()),
+                "save_extensions" => {
+                    let extensions: Vec<String> = value
+                        .split(',')
+                        .map(|ext| ext.trim().to_lowercase())
+                        .filter(|ext| !ext.is_empty())
+                        .map(|ext| {
+                            if ext.starts_with('.') {
+                                ext
+                            } else {
+                                format!(".{}", ext)
+                            }
+                        })
+                        .collect();
+                    if !extensions.is_empty() {
+                        settings.save_extensions = extensions;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if version > SETTINGS_VERSION {
+            return None;
+        }
+        settings.last_saves = last_save1.zip(last_save2);
+        Some(settings)
+    }
+
+    /// Returns the contents of the settings file.
+    pub fn serialize(&self) -> String {
+        let mut text = String::from("# nTrade Advance settings\n");
+        text.push_str(&format!("version={}\n", SETTINGS_VERSION));
+        if let Some(dir) = &self.save_dir {
+            text.push_str(&format!("save_dir={}\n", dir));
+        }
+        text.push_str(&format!("recursive={}\n", self.recursive));
+        if let Some(&(name, _)) = TEXT_COLORS.iter().find(|(_, c)| *c == self.color) {
+            text.push_str(&format!("color={}\n", name));
+        }
+        text.push_str(&format!(
+            "animation_speed={}\n",
+            self.animation_speed.name()
+        ));
+        text.push_str(&format!("backup_policy={}\n", self.backup_policy.name()));
+        text.push_str(&format!(
+            "save_extensions={}\n",
+            self.save_extensions.join(",")
+        ));
+        if let Some(language) = self.language {
+            text.push_str(&format!("language={}\n", language.code()));
+        }
+        if let Some((save1, save2)) = &self.last_saves {
+            text.push_str(&format!("last_save1={}\nlast_save2={}\n", save1, save2));
+        }
+        text
+    }
+}
+
+/// Settings that were given for this run only (command line or environment).
+/// They take precedence over the settings file and are never written to it.
+#[derive(Default)]
+struct Overrides {
+    save_dir: Option<String>,
+    recursive: Option<bool>,
+}
+
+static mut SETTINGS: Option<Settings> = None;
+/// Set if the settings file is of a newer version. It is never written then, so the
+/// settings of the newer version aren't lost.
+static mut NEWER_FILE: bool = false;
+static mut OVERRIDES: Overrides = Overrides {
+    save_dir: None,
+    recursive: None,
+};
+
+/// Loads the settings file and applies the overrides of this run.
+/// A missing or unreadable settings file, or one of a newer version, leaves the defaults
+/// in place.
+pub fn init_settings() {
+    let parsed = read_file(&settings_path())
+        .ok()
+        .map(|buf| Settings::parse(&String::from_utf8_lossy(&buf)));
+    let newer_file = matches!(parsed, Some(None));
+
+    // SAFETY: The UI runs on a single thread.
+    unsafe {
+        SETTINGS = Some(parsed.flatten().unwrap_or_default());
+        NEWER_FILE = newer_file;
+        #[cfg(not(feature = "calculator-build"))]
+        {
+            OVERRIDES = desktop_overrides();
+        }
+    }
+}
+
+/// Returns the current settings.
+#[allow(static_mut_refs)]
+pub fn settings() -> &'static Settings {
+    // SAFETY: The UI runs on a single thread and the settings are only replaced
+    // through `update_settings`.
+    unsafe { SETTINGS.get_or_insert_with(Settings::default) }
+}
+
+/// Changes the settings and writes them to the settings file.
+/// A settings file of a newer version isn't overwritten, the change only lasts for this run.
+#[allow(static_mut_refs)]
+pub fn update_settings<F: FnOnce(&mut Settings)>(update: F) -> Result<()> {
+    // SAFETY: The UI runs on a single thread.
+    let (settings, newer_file) =
+        unsafe { (SETTINGS.get_or_insert_with(Settings::default), NEWER_FILE) };
+    update(settings);
+    if newer_file {
+        return Err(AppError::NewerSettings);
+    }
+    write_file(&settings_path(), settings.serialize().as_bytes())
+}
+
+/// Sets the save directory and whether it is searched recursively.
+/// This replaces the overrides of this run, so the choice takes effect right away.
+pub fn choose_save_dir(dir: String, recursive: bool) -> Result<()> {
+    // SAFETY: The UI runs on a single thread.
+    unsafe {
+        OVERRIDES = Overrides::default();
+    }
+    forget_detected_language();
+    update_settings(|settings| {
+        settings.save_dir = Some(dir);
+        settings.recursive = recursive;
+    })
+}
+
+/// Returns the directory that is searched for saves.
+#[allow(static_mut_refs)]
+pub fn save_dir() -> String {
+    // SAFETY: The UI runs on a single thread.
+    let overridden = unsafe { OVERRIDES.save_dir.clone() };
+    overridden
+        .or_else(|| settings().save_dir.clone())
+        .unwrap_or_else(default_dir)
+}
+
+/// Returns true if subdirectories of the save directory are searched as well.
+pub fn recursive_scan() -> bool {
+    // SAFETY: The UI runs on a single thread.
+    unsafe { OVERRIDES.recursive }.unwrap_or(settings().recursive)
+}
+
+/// Reads the overrides from the command line and the environment.
+/// Prints the usage and exits on `--help` or unknown arguments.
+#[cfg(not(feature = "calculator-build"))]
+fn desktop_overrides() -> Overrides {
+    let mut overrides = Overrides {
+        save_dir: std::env::var(ENV_SAVE_DIR)
+            .ok()
+            .filter(|dir| !dir.is_empty()),
+        recursive: None,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-d" | "--dir" => match args.next() {
+                Some(dir) => overrides.save_dir = Some(dir),
+                None => exit_with_usage(2),
+            },
+            "-r" | "--recursive" => overrides.recursive = Some(true),
+            "--diff" => match (args.next(), args.next()) {
+                (Some(old), Some(new)) => exit_with_diff(&old, &new),
+                _ => exit_with_usage(2),
+            },
+            "-h" | "--help" => exit_with_usage(0),
+            _ => match arg.strip_prefix("--dir=") {
+                Some(dir) => overrides.save_dir = Some(dir.to_string()),
+                None => {
+                    eprintln!("Unknown argument: {}", arg);
+                    exit_with_usage(2)
+                }
+            },
+        }
+    }
+    overrides
+}
+
+/// Prints the command line usage and exits with the given code.
+#[cfg(not(feature = "calculator-build"))]
+fn exit_with_usage(code: i32) -> ! {
+    let usage = format!(
+        "Usage: ntradeadvance [options]\n\n\
+         Options:\n  \
+         -d, --dir <path>   Look for saves in this directory (or set {})\n  \
+         -r, --recursive    Also look for saves in subdirectories\n  \
+         --diff <old> <new> Print what changed between two save files\n  \
+         -h, --help         Show this help\n\n\
+         Settings are stored in {}",
+        ENV_SAVE_DIR,
+        settings_path()
+    );
+    if code == 0 {
+        println!("{}", usage);
+    } else {
+        eprintln!("{}", usage);
+    }
+    std::process::exit(code);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_what_it_writes() {
+        let settings = Settings {
+            save_dir: Some(String::from("/saves")),
+            recursive: true,
+            animation_speed: AnimationSpeed::Fast,
+            backup_policy: BackupPolicy::EveryWrite,
+            last_saves: Some((String::from("ruby"), String::from("emerald"))),
+            save_extensions: vec![String::from(".sav")],
+            language: Some(Language::German),
+            ..Settings::default()
+        };
+        let parsed = Settings::parse(&settings.serialize()).unwrap();
+        assert_eq!(parsed.save_dir.as_deref(), Some("/saves"));
+        assert!(parsed.recursive);
+        assert_eq!(parsed.color, settings.color);
+        assert_eq!(parsed.animation_speed, AnimationSpeed::Fast);
+        assert_eq!(parsed.backup_policy, BackupPolicy::EveryWrite);
+        assert_eq!(parsed.last_saves, settings.last_saves);
+        assert_eq!(parsed.save_extensions, settings.save_extensions);
+        assert_eq!(parsed.language, Some(Language::German));
+    }
+
+    #[test]
+    fn reads_files_without_a_version() {
+        let parsed = Settings::parse("save_dir=/saves\nrecursive=true\n").unwrap();
+        assert_eq!(parsed.save_dir.as_deref(), Some("/saves"));
+        assert!(parsed.recursive);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let newer = format!("version={}\nsave_dir=/saves\n", SETTINGS_VERSION + 1);
+        assert!(Settings::parse(&newer).is_none());
+        assert!(Settings::parse("version=two\n").is_none());
+        assert!(Settings::parse(&format!("version={}\n", SETTINGS_VERSION)).is_some());
+    }
+
+    #[test]
+    fn skips_broken_lines_and_values() {
+        let parsed = Settings::parse(
+            "# comment\nno separator\nanimation_speed=Warp\nlanguage=xx\nsave_extensions= , \nlast_save1=ruby\n",
+        )
+        .unwrap();
+        assert_eq!(parsed.animation_speed, AnimationSpeed::default());
+        assert_eq!(parsed.language, None);
+        assert_eq!(parsed.save_extensions, Settings::default().save_extensions);
+        // Only one of the two saves of the last trade is of no use.
+        assert_eq!(parsed.last_saves, None);
+    }
+
+    #[test]
+    fn normalizes_save_extensions() {
+        let parsed = Settings::parse("save_extensions=SAV, .srm,,dsv\n").unwrap();
+        assert_eq!(parsed.save_extensions, [".sav", ".srm", ".dsv"]);
+    }
+}
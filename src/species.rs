@@ -0,0 +1,548 @@
+use crate::prelude::*;
+use pkmn_savedata::core_types::PokemonSpecies;
+
+/// The number of species in the National Pokedex.
+pub const NATIONAL_DEX_SIZE: usize = 386;
+
+/// The first species index the games use for the Hoenn species.
+/// Indices between the Johto species and this one are unused placeholders.
+const FIRST_HOENN_INDEX: u16 = 277;
+
+/// The National Pokedex numbers of the Hoenn species, in the order of their species index.
+const HOENN_NATIONAL: [u16; 135] = [
+    252, 253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270,
+    271, 272, 273, 274, 275, 290, 291, 292, 276, 277, 285, 286, 327, 278, 279, 283, 284, 320, 321,
+    300, 301, 352, 343, 344, 299, 324, 302, 339, 340, 370, 341, 342, 349, 350, 318, 319, 328, 329,
+    330, 296, 297, 309, 310, 322, 323, 363, 364, 365, 331, 332, 361, 362, 337, 338, 298, 325, 326,
+    311, 312, 303, 307, 308, 333, 334, 360, 355, 356, 315, 287, 288, 289, 316, 317, 357, 293, 294,
+    295, 366, 367, 368, 359, 353, 354, 336, 335, 369, 304, 305, 306, 351, 313, 314, 345, 346, 347,
+    348, 280, 281, 282, 371, 372, 373, 374, 375, 376, 377, 378, 379, 382, 383, 384, 380, 381, 385,
+    386, 358,
+];
+
+/// Converts the species index that the games store to the National Pokedex number.
+/// Returns None for empty slots and indices that don't belong to a species.
+pub fn national_species(index: u16) -> Option<u16> {
+    match index {
+        1..=251 => Some(index),
+        FIRST_HOENN_INDEX.. => HOENN_NATIONAL
+            .get((index - FIRST_HOENN_INDEX) as usize)
+            .copied(),
+        _ => None,
+    }
+}
+
+/// Returns the name of the species with the given National Pokedex number.
+pub fn species_name(national: u16) -> String {
+    PokemonSpecies::try_from(national)
+        .map(|species| species.to_string())
+        .unwrap_or_else(|_| format!("#{:03}", national))
+}
+
+// Held items that play a part in trade evolutions.
+const KINGS_ROCK: u16 = 187;
+const DEEPSEATOOTH: u16 = 192;
+const DEEPSEASCALE: u16 = 193;
+const EVERSTONE: u16 = 195;
+const METAL_COAT: u16 = 199;
+const DRAGON_SCALE: u16 = 201;
+const UP_GRADE: u16 = 218;
+
+/// The species that evolve when they are traded, as (species, held item, evolution).
+/// The held item is 0 if the species evolves without one.
+const TRADE_EVOLUTIONS: [(u16, u16, u16); 12] = [
+    (64, 0, 65),
+    (67, 0, 68),
+    (75, 0, 76),
+    (93, 0, 94),
+    (61, KINGS_ROCK, 186),
+    (79, KINGS_ROCK, 199),
+    (95, METAL_COAT, 208),
+    (123, METAL_COAT, 212),
+    (117, DRAGON_SCALE, 230),
+    (137, UP_GRADE, 233),
+    (366, DEEPSEATOOTH, 367),
+    (366, DEEPSEASCALE, 368),
+];
+
+/// Returns the species that the Pokemon evolves into when it is traded in-game, if any.
+/// An Everstone keeps it from evolving.
+pub fn trade_evolution(pokemon: &StoredPokemon) -> Option<u16> {
+    if pokemon.is_egg || pokemon.held_item == EVERSTONE {
+        return None;
+    }
+    TRADE_EVOLUTIONS
+        .iter()
+        .find(|&&(species, item, _)| {
+            species == pokemon.species && (item == 0 || item == pokemon.held_item)
+        })
+        .map(|&(_, _, evolution)| evolution)
+}
+
+/// The types of the Pokemon, as of Gen 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PokemonType {
+    Normal,
+    Fire,
+    Water,
+    Electric,
+    Grass,
+    Ice,
+    Fighting,
+    Poison,
+    Ground,
+    Flying,
+    Psychic,
+    Bug,
+    Rock,
+    Ghost,
+    Dragon,
+    Dark,
+    Steel,
+}
+
+impl PokemonType {
+    pub const ALL: [PokemonType; 17] = [
+        PokemonType::Normal,
+        PokemonType::Fire,
+        PokemonType::Water,
+        PokemonType::Electric,
+        PokemonType::Grass,
+        PokemonType::Ice,
+        PokemonType::Fighting,
+        PokemonType::Poison,
+        PokemonType::Ground,
+        PokemonType::Flying,
+        PokemonType::Psychic,
+        PokemonType::Bug,
+        PokemonType::Rock,
+        PokemonType::Ghost,
+        PokemonType::Dragon,
+        PokemonType::Dark,
+        PokemonType::Steel,
+    ];
+
+    /// The name shown in the user interface.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            PokemonType::Normal => Text::TypeNormal,
+            PokemonType::Fire => Text::TypeFire,
+            PokemonType::Water => Text::TypeWater,
+            PokemonType::Electric => Text::TypeElectric,
+            PokemonType::Grass => Text::TypeGrass,
+            PokemonType::Ice => Text::TypeIce,
+            PokemonType::Fighting => Text::TypeFighting,
+            PokemonType::Poison => Text::TypePoison,
+            PokemonType::Ground => Text::TypeGround,
+            PokemonType::Flying => Text::TypeFlying,
+            PokemonType::Psychic => Text::TypePsychic,
+            PokemonType::Bug => Text::TypeBug,
+            PokemonType::Rock => Text::TypeRock,
+            PokemonType::Ghost => Text::TypeGhost,
+            PokemonType::Dragon => Text::TypeDragon,
+            PokemonType::Dark => Text::TypeDark,
+            PokemonType::Steel => Text::TypeSteel,
+        })
+    }
+}
+
+/// The types of each species, by National Pokedex number. Single-typed species have their
+/// type twice, like in the games.
+const SPECIES_TYPES: [[PokemonType; 2]; NATIONAL_DEX_SIZE] = {
+    use PokemonType::*;
+    [
+        [Grass, Poison],
+        [Grass, Poison],
+        [Grass, Poison],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Fire, Flying],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Bug, Bug],
+        [Bug, Bug],
+        [Bug, Flying],
+        [Bug, Poison],
+        [Bug, Poison],
+        [Bug, Poison],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Ground, Ground],
+        [Ground, Ground],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Poison, Ground],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Poison, Ground],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Poison, Flying],
+        [Poison, Flying],
+        [Grass, Poison],
+        [Grass, Poison],
+        [Grass, Poison],
+        [Bug, Grass],
+        [Bug, Grass],
+        [Bug, Poison],
+        [Bug, Poison],
+        [Ground, Ground],
+        [Ground, Ground],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Water, Water],
+        [Water, Water],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Water, Water],
+        [Water, Water],
+        [Water, Fighting],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Grass, Poison],
+        [Grass, Poison],
+        [Grass, Poison],
+        [Water, Poison],
+        [Water, Poison],
+        [Rock, Ground],
+        [Rock, Ground],
+        [Rock, Ground],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Water, Psychic],
+        [Water, Psychic],
+        [Electric, Steel],
+        [Electric, Steel],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Water, Water],
+        [Water, Ice],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Water, Water],
+        [Water, Ice],
+        [Ghost, Poison],
+        [Ghost, Poison],
+        [Ghost, Poison],
+        [Rock, Ground],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Water, Water],
+        [Water, Water],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Grass, Psychic],
+        [Grass, Psychic],
+        [Ground, Ground],
+        [Ground, Ground],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Normal, Normal],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Ground, Rock],
+        [Ground, Rock],
+        [Normal, Normal],
+        [Grass, Grass],
+        [Normal, Normal],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Water, Psychic],
+        [Psychic, Psychic],
+        [Bug, Flying],
+        [Ice, Psychic],
+        [Electric, Electric],
+        [Fire, Fire],
+        [Bug, Bug],
+        [Normal, Normal],
+        [Water, Water],
+        [Water, Flying],
+        [Water, Ice],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Water, Water],
+        [Electric, Electric],
+        [Fire, Fire],
+        [Normal, Normal],
+        [Rock, Water],
+        [Rock, Water],
+        [Rock, Water],
+        [Rock, Water],
+        [Rock, Flying],
+        [Normal, Normal],
+        [Ice, Flying],
+        [Electric, Flying],
+        [Fire, Flying],
+        [Dragon, Dragon],
+        [Dragon, Dragon],
+        [Dragon, Flying],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Grass, Grass],
+        [Grass, Grass],
+        [Grass, Grass],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Fire, Fire],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Bug, Flying],
+        [Bug, Flying],
+        [Bug, Poison],
+        [Bug, Poison],
+        [Poison, Flying],
+        [Water, Electric],
+        [Water, Electric],
+        [Electric, Electric],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Flying],
+        [Psychic, Flying],
+        [Psychic, Flying],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Grass, Grass],
+        [Water, Water],
+        [Water, Water],
+        [Rock, Rock],
+        [Water, Water],
+        [Grass, Flying],
+        [Grass, Flying],
+        [Grass, Flying],
+        [Normal, Normal],
+        [Grass, Grass],
+        [Grass, Grass],
+        [Bug, Flying],
+        [Water, Ground],
+        [Water, Ground],
+        [Psychic, Psychic],
+        [Dark, Dark],
+        [Dark, Flying],
+        [Water, Psychic],
+        [Ghost, Ghost],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Normal, Psychic],
+        [Bug, Bug],
+        [Bug, Steel],
+        [Normal, Normal],
+        [Ground, Flying],
+        [Steel, Ground],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Water, Poison],
+        [Bug, Steel],
+        [Bug, Rock],
+        [Bug, Fighting],
+        [Dark, Ice],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Fire, Fire],
+        [Fire, Rock],
+        [Ice, Ground],
+        [Ice, Ground],
+        [Water, Rock],
+        [Water, Water],
+        [Water, Water],
+        [Ice, Flying],
+        [Water, Flying],
+        [Steel, Flying],
+        [Dark, Fire],
+        [Dark, Fire],
+        [Water, Dragon],
+        [Ground, Ground],
+        [Ground, Ground],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Ice, Psychic],
+        [Electric, Electric],
+        [Fire, Fire],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Electric, Electric],
+        [Fire, Fire],
+        [Water, Water],
+        [Rock, Ground],
+        [Rock, Ground],
+        [Rock, Dark],
+        [Psychic, Flying],
+        [Fire, Flying],
+        [Psychic, Grass],
+        [Grass, Grass],
+        [Grass, Grass],
+        [Grass, Grass],
+        [Fire, Fire],
+        [Fire, Fighting],
+        [Fire, Fighting],
+        [Water, Water],
+        [Water, Ground],
+        [Water, Ground],
+        [Dark, Dark],
+        [Dark, Dark],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Bug, Bug],
+        [Bug, Bug],
+        [Bug, Flying],
+        [Bug, Bug],
+        [Bug, Poison],
+        [Water, Grass],
+        [Water, Grass],
+        [Water, Grass],
+        [Grass, Grass],
+        [Grass, Dark],
+        [Grass, Dark],
+        [Normal, Flying],
+        [Normal, Flying],
+        [Water, Flying],
+        [Water, Flying],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Bug, Water],
+        [Bug, Flying],
+        [Grass, Grass],
+        [Grass, Fighting],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Bug, Ground],
+        [Bug, Flying],
+        [Bug, Ghost],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Fighting, Fighting],
+        [Fighting, Fighting],
+        [Normal, Normal],
+        [Rock, Rock],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Dark, Ghost],
+        [Steel, Steel],
+        [Steel, Rock],
+        [Steel, Rock],
+        [Steel, Rock],
+        [Fighting, Psychic],
+        [Fighting, Psychic],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Electric, Electric],
+        [Bug, Bug],
+        [Bug, Bug],
+        [Grass, Poison],
+        [Poison, Poison],
+        [Poison, Poison],
+        [Water, Dark],
+        [Water, Dark],
+        [Water, Water],
+        [Water, Water],
+        [Fire, Ground],
+        [Fire, Ground],
+        [Fire, Fire],
+        [Psychic, Psychic],
+        [Psychic, Psychic],
+        [Normal, Normal],
+        [Ground, Ground],
+        [Ground, Dragon],
+        [Ground, Dragon],
+        [Grass, Grass],
+        [Grass, Dark],
+        [Normal, Flying],
+        [Dragon, Flying],
+        [Normal, Normal],
+        [Poison, Poison],
+        [Rock, Psychic],
+        [Rock, Psychic],
+        [Water, Ground],
+        [Water, Ground],
+        [Water, Water],
+        [Water, Dark],
+        [Ground, Psychic],
+        [Ground, Psychic],
+        [Rock, Grass],
+        [Rock, Grass],
+        [Rock, Bug],
+        [Rock, Bug],
+        [Water, Water],
+        [Water, Water],
+        [Normal, Normal],
+        [Normal, Normal],
+        [Ghost, Ghost],
+        [Ghost, Ghost],
+        [Ghost, Ghost],
+        [Ghost, Ghost],
+        [Grass, Flying],
+        [Psychic, Psychic],
+        [Dark, Dark],
+        [Psychic, Psychic],
+        [Ice, Ice],
+        [Ice, Ice],
+        [Ice, Water],
+        [Ice, Water],
+        [Ice, Water],
+        [Water, Water],
+        [Water, Water],
+        [Water, Water],
+        [Water, Rock],
+        [Water, Water],
+        [Dragon, Dragon],
+        [Dragon, Dragon],
+        [Dragon, Flying],
+        [Steel, Psychic],
+        [Steel, Psychic],
+        [Steel, Psychic],
+        [Rock, Rock],
+        [Ice, Ice],
+        [Steel, Steel],
+        [Dragon, Psychic],
+        [Dragon, Psychic],
+        [Water, Water],
+        [Ground, Ground],
+        [Dragon, Flying],
+        [Steel, Psychic],
+        [Psychic, Psychic],
+    ]
+};
+
+/// Returns the types of the species with the given National Pokedex number.
+/// The second type is None for single-typed species.
+pub fn species_types(national: u16) -> Option<(PokemonType, Option<PokemonType>)> {
+    let [first, second] = *SPECIES_TYPES.get((national as usize).checked_sub(1)?)?;
+    Some((first, (second != first).then_some(second)))
+}
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+
+// Offsets in the trainer info (sector 0).
+const OFFSET_GENDER: usize = 0x08;
+const OFFSET_TRAINER_ID: usize = 0x0A;
+const OFFSET_PLAY_TIME: usize = 0x0E;
+const OFFSET_GAME_CODE: usize = 0xAC;
+const OFFSET_FRLG_SECURITY_KEY: usize = 0xAF8;
+
+/// The trainer information of a save, as shown on the in-game trainer card.
+#[derive(Clone, Debug)]
+pub struct TrainerCard {
+    pub version: GameVersion,
+    pub name: String,
+    pub female: bool,
+    pub public_id: u16,
+    pub secret_id: u16,
+    /// Play time as (hours, minutes, seconds).
+    pub play_time: (u16, u8, u8),
+    pub money: u32,
+    /// The gym badges, in the order of the gyms.
+    pub badges: [bool; 8],
+    pub dex_seen: usize,
+    pub dex_caught: usize,
+    pub hall_of_fame: bool,
+}
+
+/// Reads the trainer card of the save with the given name.
+pub fn read_trainer_card(name: &str) -> Result<TrainerCard> {
+    let loaded = load_save(name)?;
+    Ok(trainer_card(&loaded.blocks, trainer_name(&loaded.save)))
+}
+
+/// Reads the trainer card from the blocks of a save. The name is decoded by the caller.
+pub fn trainer_card(blocks: &SaveBlocks, name: String) -> TrainerCard {
+    let info = &blocks.trainer;
+    let version = blocks.version;
+    let security_key = match version {
+        GameVersion::RubySapphire => 0,
+        GameVersion::FireRedLeafGreen => read_u32(info, OFFSET_FRLG_SECURITY_KEY),
+        GameVersion::Emerald => read_u32(info, OFFSET_GAME_CODE),
+    };
+
+    let trainer_id = read_u32(info, OFFSET_TRAINER_ID);
+    let mut badges = [false; 8];
+    for (i, badge) in badges.iter_mut().enumerate() {
+        *badge = blocks.flag(version.first_badge_flag() + i);
+    }
+    let pokedex = Pokedex::read(blocks);
+
+    TrainerCard {
+        version,
+        name,
+        female: info[OFFSET_GENDER] == 1,
+        public_id: trainer_id as u16,
+        secret_id: (trainer_id >> 16) as u16,
+        play_time: (
+            u16::from_le_bytes([info[OFFSET_PLAY_TIME], info[OFFSET_PLAY_TIME + 1]]),
+            info[OFFSET_PLAY_TIME + 2],
+            info[OFFSET_PLAY_TIME + 3],
+        ),
+        money: read_u32(&blocks.state, version.money_offset()) ^ security_key,
+        badges,
+        dex_seen: pokedex.seen_count(),
+        dex_caught: pokedex.caught_count(),
+        hall_of_fame: blocks.flag(version.game_clear_flag()),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+/// A single frame of an ASCII animation as (duration in ms, image, color).
+/// Frames without a color are drawn in the screen's base color.
+pub type Frame = (u32, &'static str, Option<u8>);
+
+/// Plays a fixed sequence of frames, driven by `Screen::tick`.
+pub struct FrameAnimation {
+    frames: &'static [Frame],
+    index: usize,
+    /// Time spent on the current frame.
+    elapsed: u32,
+}
+
+impl FrameAnimation {
+    pub fn new(frames: &'static [Frame]) -> Self {
+        Self {
+            frames,
+            index: 0,
+            elapsed: 0,
+        }
+    }
+
+    /// Returns the frame that should currently be shown.
+    pub fn frame(&self) -> Frame {
+        self.frames[self.index.min(self.frames.len() - 1)]
+    }
+
+    /// Advances the animation by `dt` milliseconds, at the speed set in the settings.
+    /// Returns false once the last frame has been shown for its full duration.
+    pub fn advance(&mut self, dt: u32) -> bool {
+        let speed = settings().animation_speed;
+        self.elapsed += dt;
+        while self.index < self.frames.len()
+            && self.elapsed >= speed.duration(self.frames[self.index].0)
+        {
+            self.elapsed -= speed.duration(self.frames[self.index].0);
+            self.index += 1;
+        }
+        self.index < self.frames.len()
+    }
+}
@@ -0,0 +1,133 @@
+use crate::platform::input::InputKey;
+#[cfg(feature = "calculator-build")]
+use crate::prelude::*;
+use crate::ui::rendering::{hit_test, tap_targets, Hit, Rect};
+
+/// What a `FocusManager` did with an input key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusEvent {
+    /// The key wasn't handled, the screen may use it.
+    None,
+    /// The focus moved to another widget.
+    Moved,
+    /// The focused widget was activated with Enter.
+    Activated(usize),
+    /// A widget was tapped. It is focused now and should be activated.
+    Tapped(Hit),
+}
+
+impl FocusEvent {
+    /// Returns the id of the widget that was activated with Enter or a tap.
+    pub fn activated(&self) -> Option<usize> {
+        match self {
+            FocusEvent::Activated(id) | FocusEvent::Tapped(Hit { id, .. }) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+/// Tracks which widget has the focus and moves it with the arrow keys.
+///
+/// Focusable widgets are the tap targets of the last rendered frame, identified by their
+/// ids. Screens ask `is_focused` while building the ui and pass their input through
+/// `handle_input`, which moves the focus to the nearest widget in the pressed direction.
+#[derive(Clone, Debug)]
+pub struct FocusManager {
+    focused: Option<usize>,
+}
+
+impl FocusManager {
+    /// Creates a focus manager that starts with the given widget focused.
+    pub fn new(initial: usize) -> Self {
+        Self {
+            focused: Some(initial),
+        }
+    }
+
+    /// Returns the id of the focused widget.
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns true if the widget with the given id has the focus.
+    pub fn is_focused(&self, id: usize) -> bool {
+        self.focused == Some(id)
+    }
+
+    /// Moves the focus to the widget with the given id.
+    pub fn focus(&mut self, id: usize) {
+        self.focused = Some(id);
+    }
+
+    /// Handles arrow keys, Enter and taps.
+    pub fn handle_input(&mut self, input: &InputKey) -> FocusEvent {
+        let direction = match input {
+            InputKey::Up => (0, -1),
+            InputKey::Down => (0, 1),
+            InputKey::Left => (-1, 0),
+            InputKey::Right => (1, 0),
+            InputKey::Enter => {
+                return match self.focused {
+                    Some(id) => FocusEvent::Activated(id),
+                    None => FocusEvent::None,
+                }
+            }
+            InputKey::Pointer(x, y) => {
+                return match hit_test(*x, *y) {
+                    Some(hit) => {
+                        self.focused = Some(hit.id);
+                        FocusEvent::Tapped(hit)
+                    }
+                    None => FocusEvent::None,
+                }
+            }
+            _ => return FocusEvent::None,
+        };
+
+        let targets = tap_targets();
+        let current = targets
+            .iter()
+            .find(|(id, _)| Some(*id) == self.focused)
+            .map(|(_, rect)| *rect);
+        let next = match current {
+            Some(current) => nearest(&targets, &current, direction),
+            // The focused widget is gone (or nothing was focused yet), start over.
+            None => targets.first().map(|(id, _)| *id),
+        };
+        match next {
+            Some(id) => {
+                self.focused = Some(id);
+                FocusEvent::Moved
+            }
+            None => FocusEvent::None,
+        }
+    }
+}
+
+/// Finds the target closest to `from` that lies entirely in the given direction.
+///
+/// The distance along the direction counts twice as much as the offset across it,
+/// so focus prefers to move straight ahead.
+fn nearest(targets: &[(usize, Rect)], from: &Rect, (dx, dy): (i32, i32)) -> Option<usize> {
+    // Edges and centers are compared in doubled coordinates to avoid rounding.
+    let left = |r: &Rect| 2 * r.x as i32;
+    let right = |r: &Rect| 2 * (r.x + r.width) as i32;
+    let top = |r: &Rect| 2 * r.y as i32;
+    let bottom = |r: &Rect| 2 * (r.y + r.height) as i32;
+    let center_x = |r: &Rect| (left(r) + right(r)) / 2;
+    let center_y = |r: &Rect| (top(r) + bottom(r)) / 2;
+
+    targets
+        .iter()
+        .filter_map(|(id, rect)| {
+            let (gap, offset) = match (dx, dy) {
+                (0, -1) => (top(from) - bottom(rect), center_x(rect) - center_x(from)),
+                (0, 1) => (top(rect) - bottom(from), center_x(rect) - center_x(from)),
+                (-1, 0) => (left(from) - right(rect), center_y(rect) - center_y(from)),
+                _ => (left(rect) - right(from), center_y(rect) - center_y(from)),
+            };
+            (gap >= 0).then_some((*id, 2 * gap + offset.abs()))
+        })
+        .min_by_key(|(_, score)| *score)
+        .map(|(id, _)| id)
+}
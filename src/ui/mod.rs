@@ -1,3 +1,5 @@
+pub mod animation;
+pub mod focus;
 pub mod rendering;
 pub mod screens;
 pub mod theme;
@@ -1,7 +1,9 @@
+use crate::platform::console;
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
+use core::cell::Cell;
 
-/// Terminal dimensions for rendering.
+/// Default terminal dimensions, used until the console reports its actual size.
 pub const NIO_MAX_ROWS: u16 = 30; // NOTE: nspireio.h says 27, real device says 30
 pub const NIO_MAX_COLS: u16 = 53; // NOTE: nspireio.h says 64, real device says 53.5
 
@@ -11,12 +13,16 @@ pub const NIO_MAX_COLS: u16 = 53; // NOTE: nspireio.h says 64, real device says
 pub const WIPE_CHAR: char = '\u{E000}';
 
 /// The `Widget` trait defines the interface for all UI widgets.
+///
+/// Layout happens in two phases: `min_size` measures the tree bottom-up, then `render`
+/// lays it out top-down. Widget trees are rebuilt every frame, so widgets cache their
+/// measured size in a `SizeCache` and each node is only measured once per frame.
 pub trait Widget {
     /// Returns the minimum (width, height) required by the widget.
     fn min_size(&self) -> (u16, u16);
 
-    /// Renders the widget into a canvas (a 2D vector of characters) of given width and height.
-    fn render(&self, width: u16, height: u16) -> Canvas;
+    /// Renders the widget into the given canvas, using the canvas' width and height.
+    fn render(&self, canvas: &mut Canvas<'_>);
 
     /// Returns an optional flex factor for layout. Default is `None`.
     fn flex_factor(&self) -> Option<i32> {
@@ -24,63 +30,289 @@ pub trait Widget {
     }
 }
 
-/// A 2D canvas is represented as a vector of character rows.
-pub type Canvas = Vec<Vec<char>>;
+/// Caches the measured size of a widget for the lifetime of the widget tree (one frame).
+#[derive(Default)]
+pub struct SizeCache(Cell<Option<(u16, u16)>>);
+
+impl SizeCache {
+    pub fn new() -> Self {
+        Self(Cell::new(None))
+    }
 
-/// Creates a blank canvas filled with spaces.
-pub fn create_canvas(width: u16, height: u16) -> Canvas {
-    let mut canvas = Vec::new();
-    for _ in 0..height {
-        canvas.push(vec![' '; width as usize]);
+    /// Returns the cached size, measuring it with `measure` on first use.
+    pub fn get_or_measure<F: FnOnce() -> (u16, u16)>(&self, measure: F) -> (u16, u16) {
+        if let Some(size) = self.0.get() {
+            return size;
+        }
+        let size = measure();
+        self.0.set(Some(size));
+        size
     }
-    canvas
 }
 
-/// Converts a canvas into a single string with newline separators.
-/// If a cell contains WIPE_CHAR, it is printed as a space.
-pub fn canvas_to_string(canvas: &Canvas) -> String {
-    canvas
-        .iter()
-        .map(|row| {
-            row.iter()
-                .map(|&ch| if ch == WIPE_CHAR { ' ' } else { ch })
-                .collect::<String>()
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-/// Overlays a child canvas onto a parent canvas at (offset_x, offset_y).
-/// If a cell in the child canvas equals `WIPE_CHAR`, the parent's cell is replaced with a space.
-/// Otherwise, non-space characters in the child override the parent's content.
-pub fn overlay(canvas: &mut Canvas, child: &Canvas, offset_x: u16, offset_y: u16) {
-    let parent_height = canvas.len();
-    let parent_width = if parent_height > 0 {
-        canvas[0].len()
-    } else {
-        0
-    };
-    for (j, child_row) in child.iter().enumerate() {
-        let y = j as u16 + offset_y;
-        if y >= parent_height as u16 {
-            break;
+/// A flat width×height character buffer that a whole frame is rendered into.
+///
+/// The buffer and the output string are kept across frames, so rendering a frame
+/// doesn't allocate once the screen size has settled.
+pub struct Framebuffer {
+    width: u16,
+    height: u16,
+    cells: Vec<char>,
+    output: String,
+    /// The tap targets of the frame, in render order.
+    targets: Vec<TapRegion>,
+}
+
+impl Framebuffer {
+    pub const fn new() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+            output: String::new(),
+            targets: Vec::new(),
         }
-        for (i, &ch) in child_row.iter().enumerate() {
-            let x = i as u16 + offset_x;
-            if x >= parent_width as u16 {
-                break;
+    }
+
+    /// Resizes the buffer if needed and fills it with spaces.
+    pub fn reset(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.cells.clear();
+        self.cells.resize(width as usize * height as usize, ' ');
+        self.targets.clear();
+    }
+
+    /// Returns a canvas covering the whole buffer.
+    pub fn canvas(&mut self) -> Canvas<'_> {
+        Canvas {
+            cells: &mut self.cells,
+            targets: &mut self.targets,
+            stride: self.width as usize,
+            origin_x: 0,
+            origin_y: 0,
+            width: self.width,
+            height: self.height,
+            clip: Rect {
+                x: 0,
+                y: 0,
+                width: self.width,
+                height: self.height,
+            },
+        }
+    }
+
+    /// Converts the buffer into a single string with newline separators.
+    /// If a cell contains WIPE_CHAR, it is printed as a space.
+    pub fn canvas_to_string(&mut self) -> &str {
+        self.output.clear();
+        for (y, row) in self.cells.chunks(self.width.max(1) as usize).enumerate() {
+            if y > 0 {
+                self.output.push('\n');
             }
-            if ch != ' ' {
-                canvas[y as usize][x as usize] = ch;
+            self.output
+                .extend(row.iter().map(|&ch| if ch == WIPE_CHAR { ' ' } else { ch }));
+        }
+        &self.output
+    }
+
+    /// Returns the topmost tap target at the given absolute cell.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<Hit> {
+        // Targets rendered later are drawn on top of earlier ones.
+        self.targets
+            .iter()
+            .rev()
+            .find(|target| target.clip.contains(x, y))
+            .map(|target| Hit {
+                id: target.id,
+                x: x - target.origin_x,
+                y: y - target.origin_y,
+            })
+    }
+}
+
+/// The laid-out area of a tap target, as reported while rendering.
+struct TapRegion {
+    id: usize,
+    origin_x: u16,
+    origin_y: u16,
+    clip: Rect,
+}
+
+/// The result of a hit test: the id of the tap target and the tapped cell,
+/// relative to the target's top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub id: usize,
+    pub x: u16,
+    pub y: u16,
+}
+
+/// A rectangle in absolute framebuffer coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    /// Returns the overlapping part of two rectangles.
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        Rect {
+            x,
+            y,
+            width: right.saturating_sub(x),
+            height: bottom.saturating_sub(y),
+        }
+    }
+
+    /// Returns true if the given absolute cell lies inside the rectangle.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+/// A clipped view into a `Framebuffer` that a widget draws into.
+///
+/// Coordinates are relative to the view. The view may be larger than the visible area
+/// (e.g. a border that is rendered at its minimum size), anything outside the clip
+/// rectangle is discarded.
+pub struct Canvas<'a> {
+    cells: &'a mut [char],
+    targets: &'a mut Vec<TapRegion>,
+    stride: usize,
+    origin_x: u16,
+    origin_y: u16,
+    width: u16,
+    height: u16,
+    clip: Rect,
+}
+
+impl<'a> Canvas<'a> {
+    /// The width of the view.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height of the view.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The visible area of the view in absolute framebuffer coordinates.
+    #[allow(dead_code)]
+    pub fn clip(&self) -> Rect {
+        self.clip
+    }
+
+    /// Draws a character at (x, y).
+    /// Spaces are transparent and leave the underlying cell untouched. To erase a cell,
+    /// draw `WIPE_CHAR` instead.
+    pub fn put(&mut self, x: u16, y: u16, ch: char) {
+        if ch == ' ' || x >= self.width || y >= self.height {
+            return;
+        }
+        let abs_x = self.origin_x + x;
+        let abs_y = self.origin_y + y;
+        if self.clip.contains(abs_x, abs_y) {
+            self.cells[abs_y as usize * self.stride + abs_x as usize] = ch;
+        }
+    }
+
+    /// Draws a string horizontally, starting at (x, y).
+    pub fn put_str(&mut self, x: u16, y: u16, s: &str) {
+        for (i, ch) in s.chars().enumerate() {
+            if x as usize + i >= self.width as usize {
+                break;
             }
+            self.put(x + i as u16, y, ch);
+        }
+    }
+
+    /// Reports the visible area of this view as a tap target with the given id.
+    /// Invisible views are not reported.
+    pub fn mark(&mut self, id: usize) {
+        if self.clip.width == 0 || self.clip.height == 0 {
+            return;
+        }
+        self.targets.push(TapRegion {
+            id,
+            origin_x: self.origin_x,
+            origin_y: self.origin_y,
+            clip: self.clip,
+        });
+    }
+
+    /// Returns a sub-view at (x, y) with the given size, clipped to this view.
+    pub fn sub(&mut self, x: u16, y: u16, width: u16, height: u16) -> Canvas<'_> {
+        let origin_x = self.origin_x + x.min(self.width);
+        let origin_y = self.origin_y + y.min(self.height);
+        let clip = self.clip.intersect(&Rect {
+            x: origin_x,
+            y: origin_y,
+            width,
+            height,
+        });
+        Canvas {
+            cells: &mut *self.cells,
+            targets: &mut *self.targets,
+            stride: self.stride,
+            origin_x,
+            origin_y,
+            width,
+            height,
+            clip,
         }
     }
 }
 
-/// Renders a ui into a string using the default terminal dimensions.
-pub fn render_ui<W: Widget>(widget: W) -> String {
-    let canvas = widget.render(NIO_MAX_COLS, NIO_MAX_ROWS);
-    canvas_to_string(&canvas)
+/// The framebuffer shared by all frames.
+static mut FRAMEBUFFER: Framebuffer = Framebuffer::new();
+
+/// Renders a ui into the shared framebuffer using the current console dimensions
+/// and presents it on the console.
+pub fn render_ui<W: Widget>(widget: W) {
+    // Measure pass: fills the size caches of the whole tree bottom-up.
+    widget.min_size();
+
+    // SAFETY: The UI runs on a single thread and the reference doesn't outlive this call.
+    #[allow(static_mut_refs)]
+    let framebuffer = unsafe { &mut FRAMEBUFFER };
+    let (width, height) = console::size();
+    framebuffer.reset(width, height);
+
+    // Layout pass: every node reads its children's cached sizes.
+    widget.render(&mut framebuffer.canvas());
+    console::present(framebuffer.canvas_to_string());
+}
+
+/// Returns the tap target under the given cell of the last rendered frame, if any.
+///
+/// Screens call this with the position of an `InputKey::Pointer` to find out what was
+/// tapped. Mark widgets as tap targets with the `tap_target` widget.
+pub fn hit_test(x: u16, y: u16) -> Option<Hit> {
+    // SAFETY: The UI runs on a single thread and the reference doesn't outlive this call.
+    #[allow(static_mut_refs)]
+    let framebuffer = unsafe { &FRAMEBUFFER };
+    framebuffer.hit_test(x, y)
+}
+
+/// Returns the visible areas of all tap targets of the last rendered frame, in render order.
+pub fn tap_targets() -> Vec<(usize, Rect)> {
+    // SAFETY: The UI runs on a single thread and the reference doesn't outlive this call.
+    #[allow(static_mut_refs)]
+    let framebuffer = unsafe { &FRAMEBUFFER };
+    framebuffer
+        .targets
+        .iter()
+        .map(|target| (target.id, target.clip))
+        .collect()
 }
 
 /// The `IntoWidget` trait allows conversion into a boxed widget.
@@ -12,36 +12,32 @@ impl Screen for AboutScreen {
             text(""),
             text(""),
             align(text("Philipp Bauer")),
-            align(text("Made in Germany in 2025")),
+            align(text(tr(Text::MadeIn))),
             text(""),
             align(
                 border(
-                    padding(
-                        sizedbox(
-                            text(
-                                "This application is provided without any warranty. Make sure you have backups of your save files before using it!"
-                            )
-                            .max_width(40),
-                        ).width(40),
-                    )
-                    .horizontal(1)
+                    padding(sizedbox(text(tr(Text::Warranty)).max_width(40),).width(40),)
+                        .horizontal(1)
                 )
                 .corners(CORNERS_ROUND)
             ),
             text(""),
-            align(text("The original version from 2014 was written")),
-            align(text("in c, v2.0.0 onwards is written in Rust.")),
+            align(text(tr(Text::History1))),
+            align(text(tr(Text::History2))),
             text(""),
             text(""),
             padding(column(widget_vec![
                 align(column(widget_vec![
-                    text("Special thanks to:"),
+                    text(tr(Text::SpecialThanks)),
                     divider('-'),
                 ]))
                 .horizontal(AlignHorizontal::Start),
-                text("- pkmn-savedata by Zayaldrie"),
-                text("- ndless-rs by Ben Schattinger (lights0123)"),
-                text("- Ndless by the Ndless team"),
+                text(trf(Text::ThanksTo, &["pkmn-savedata", "Zayaldrie"])),
+                text(trf(
+                    Text::ThanksTo,
+                    &["ndless-rs", "Ben Schattinger (lights0123)"]
+                )),
+                text(trf(Text::ThanksTo, &["Ndless", tr(Text::NdlessTeam)])),
             ]))
             .left(3),
             text(""),
@@ -83,13 +79,10 @@ impl Screen for AboutScreen {
             ),
         ]));
 
-        let output = render_ui(ui);
-        console::print(&output);
-        console::flush();
+        render_ui(ui);
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        let input = wait_input();
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
         match input {
             InputKey::Escape | InputKey::Enter => Ok(NavAction::Pop),
             _ => Ok(NavAction::None),
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+// Focus ids.
+const NO: usize = 0;
+const YES: usize = 1;
+
+/// A yes/no dialog. Pops with `true` if the user confirmed and `false` otherwise.
+pub struct ConfirmScreen {
+    question: String,
+    yes: String,
+    no: String,
+    focus: FocusManager,
+}
+
+impl ConfirmScreen {
+    pub fn new<S: Into<String>>(question: S) -> Self {
+        ConfirmScreen {
+            question: question.into(),
+            yes: String::from(tr(Text::Yes)),
+            no: String::from(tr(Text::No)),
+            focus: FocusManager::new(NO),
+        }
+    }
+
+    /// Sets the button labels.
+    pub fn labels<S: Into<String>>(mut self, yes: S, no: S) -> Self {
+        self.yes = yes.into();
+        self.no = no.into();
+        self
+    }
+}
+
+impl Screen for ConfirmScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let ui = border(column(widget_vec![
+            flexible(
+                1,
+                align(
+                    border(padding(text(&self.question).max_width(40)).horizontal(1))
+                        .corners(CORNERS_ROUND),
+                ),
+            ),
+            align(row(widget_vec![
+                sizedbox(button(&self.no).focus(&self.focus, NO))
+                    .width(12)
+                    .height(3),
+                text("   "),
+                sizedbox(button(&self.yes).focus(&self.focus, YES))
+                    .width(12)
+                    .height(3),
+            ])),
+            text(""),
+            text(""),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::pop_with(false));
+        }
+        match self.focus.handle_input(&input).activated() {
+            Some(id) => Ok(NavAction::pop_with(id == YES)),
+            None => Ok(NavAction::None),
+        }
+    }
+}
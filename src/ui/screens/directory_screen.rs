@@ -0,0 +1,224 @@
+use crate::prelude::*;
+
+// Focus ids. The entries of the list are numbered from `FIRST_ENTRY`.
+const CANCEL: usize = 0;
+const RECURSIVE: usize = 1;
+const USE: usize = 2;
+const FIRST_ENTRY: usize = 100;
+
+/// Rows taken by everything except the list: borders, title, path, buttons and message.
+const CHROME_HEIGHT: u16 = 15;
+
+/// The result of a `DirectoryScreen` after the user picked a directory.
+pub struct DirectoryChosen {
+    pub path: String,
+    /// Whether subdirectories should be searched as well.
+    pub recursive: bool,
+}
+
+/// Lets the user browse the file system and pick the directory to look for saves in.
+pub struct DirectoryScreen {
+    path: String,
+    /// The subdirectories of `path` as (label, path), starting with the parent directory.
+    entries: Vec<(String, String)>,
+    /// The index of the first visible entry.
+    first: usize,
+    recursive: bool,
+    focus: FocusManager,
+    message: String,
+}
+
+impl DirectoryScreen {
+    /// Creates a directory browser that starts in the given directory.
+    pub fn new<S: Into<String>>(path: S, recursive: bool) -> Self {
+        DirectoryScreen {
+            path: path.into(),
+            entries: Vec::new(),
+            first: 0,
+            recursive,
+            focus: FocusManager::new(FIRST_ENTRY),
+            message: String::new(),
+        }
+    }
+
+    /// Switches to another directory and lists its subdirectories.
+    fn open(&mut self, path: String) -> Result<()> {
+        let mut dirs: Vec<String> = read_dir(&path)?
+            .into_iter()
+            .filter(|entry| !file_name(entry).starts_with('.') && is_dir(entry))
+            .collect();
+        dirs.sort();
+
+        self.entries = parent_dir(&path)
+            .map(|parent| (String::from(".."), parent))
+            .into_iter()
+            .chain(dirs.into_iter().map(|dir| (file_name(&dir), dir)))
+            .collect();
+        self.path = path;
+        self.first = 0;
+        self.focus.focus(FIRST_ENTRY);
+        Ok(())
+    }
+
+    /// Returns the index of the focused entry, if an entry has the focus.
+    fn focused_entry(&self) -> Option<usize> {
+        self.focus
+            .focused()
+            .and_then(|id| id.checked_sub(FIRST_ENTRY))
+            .filter(|&i| i < self.entries.len())
+    }
+
+    /// Focuses an entry and scrolls the list so it is visible.
+    fn select(&mut self, index: usize) {
+        let visible = visible_entries();
+        if index < self.first {
+            self.first = index;
+        } else if index >= self.first + visible {
+            self.first = index + 1 - visible;
+        }
+        self.focus.focus(FIRST_ENTRY + index);
+    }
+}
+
+impl Screen for DirectoryScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        // The configured directory may be gone, start over from the default one.
+        if self.open(self.path.clone()).is_err() {
+            self.open(default_dir())?;
+        }
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let (cols, _) = console::size();
+        // Outer border (2), list border (2) and the highlight brackets (4).
+        let name_width = cols.saturating_sub(8) as usize;
+
+        let rows = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(self.first)
+            .take(visible_entries())
+            .map(|(i, (label, _))| {
+                let label = shorten(label, name_width);
+                let row = if self.focus.is_focused(FIRST_ENTRY + i) {
+                    format!("[ {:w$} ]", label, w = name_width)
+                } else {
+                    format!("  {:w$}  ", label, w = name_width)
+                };
+                tap_target(FIRST_ENTRY + i, text(row))
+            })
+            .collect();
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(tr(Text::ChooseSaveFolder)))).height(3),
+            align(text(shorten(&self.path, cols.saturating_sub(4) as usize))),
+            text(""),
+            align(
+                border(sizedbox(column(rows)).height(visible_entries() as u16))
+                    .corners(CORNERS_ROUND)
+            ),
+            text(""),
+            align(row(widget_vec![
+                sizedbox(button(tr(Text::Cancel)).focus(&self.focus, CANCEL)).width(10),
+                text(" "),
+                sizedbox(
+                    button(tr(if self.recursive {
+                        Text::SubfoldersOn
+                    } else {
+                        Text::SubfoldersOff
+                    }))
+                    .focus(&self.focus, RECURSIVE)
+                )
+                .width(19),
+                text(" "),
+                sizedbox(button(tr(Text::Use)).focus(&self.focus, USE)).width(10),
+            ])),
+            flexible(1, align(text(&self.message).max_width(40))),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        self.message = String::new();
+
+        // The list scrolls, so moving inside it can't rely on the visible entries alone.
+        if let Some(i) = self.focused_entry() {
+            match input {
+                InputKey::Up if i > 0 => {
+                    self.select(i - 1);
+                    return Ok(NavAction::None);
+                }
+                InputKey::Down if i + 1 < self.entries.len() => {
+                    self.select(i + 1);
+                    return Ok(NavAction::None);
+                }
+                _ => {}
+            }
+        }
+
+        match input {
+            InputKey::Escape => return Ok(NavAction::Pop),
+            // Backspace goes up one directory, like in most file managers.
+            InputKey::Backspace => {
+                if let Some(parent) = parent_dir(&self.path) {
+                    if let Err(e) = self.open(parent) {
+                        self.message = e.to_string();
+                    }
+                }
+                return Ok(NavAction::None);
+            }
+            _ => {}
+        }
+
+        match self.focus.handle_input(&input).activated() {
+            Some(CANCEL) => return Ok(NavAction::Pop),
+            Some(RECURSIVE) => self.recursive = !self.recursive,
+            Some(USE) => {
+                return Ok(NavAction::pop_with(DirectoryChosen {
+                    path: self.path.clone(),
+                    recursive: self.recursive,
+                }))
+            }
+            Some(id) if id >= FIRST_ENTRY => {
+                if let Some((_, path)) = self.entries.get(id - FIRST_ENTRY).cloned() {
+                    if let Err(e) = self.open(path) {
+                        self.message = e.to_string();
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(NavAction::None)
+    }
+}
+
+/// Returns how many entries of the list fit on the screen.
+fn visible_entries() -> usize {
+    let (_, rows) = console::size();
+    rows.saturating_sub(CHROME_HEIGHT).max(3) as usize
+}
+
+/// Shortens a path to the given width, keeping its end.
+fn shorten(path: &str, width: usize) -> String {
+    let len = path.chars().count();
+    if len <= width {
+        return path.to_string();
+    }
+    let tail: String = path.chars().skip(len + 3 - width.max(3)).collect();
+    format!("...{}", tail)
+}
@@ -1,8 +1,103 @@
 use crate::prelude::*;
+use crate::ui::rendering::IntoWidget;
 
-#[derive(Debug)]
+// Focus ids. The recovery buttons are numbered from `FIRST_RECOVERY`.
+const OK: usize = 0;
+const DETAILS: usize = 1;
+const FIRST_RECOVERY: usize = 10;
+
+/// Shows an error with its code and the ways out of it that the error declares. The
+/// details list the context of the error and its cause, which is what a bug report needs.
 pub struct ErrorScreen {
     pub error: AppError,
+    /// The screen that failed to open, opened again by `Recovery::Retry`.
+    retry: Option<Box<dyn Screen>>,
+    /// Replaces the message once a recovery has fixed the problem.
+    notice: Option<String>,
+    /// Whether the details are shown instead of the message.
+    details: bool,
+    /// The saves whose backups are restored once the user confirms it.
+    restoring: Vec<String>,
+    /// Whether the backups of the saves that the error can restore could all be read.
+    /// It is checked once, as the backups are whole save files.
+    backups_readable: bool,
+    focus: FocusManager,
+}
+
+impl ErrorScreen {
+    pub fn new(error: AppError) -> Self {
+        let backups_readable = error.recoveries().iter().all(|recovery| match recovery {
+            Recovery::RestoreBackup(saves) => saves
+                .iter()
+                .all(|save| read_file(&backup_file_path(save)).is_ok()),
+            _ => true,
+        });
+        ErrorScreen {
+            error,
+            retry: None,
+            notice: None,
+            details: false,
+            restoring: Vec::new(),
+            backups_readable,
+            // Moved to OK while building if there is no recovery.
+            focus: FocusManager::new(FIRST_RECOVERY),
+        }
+    }
+
+    /// Sets the screen that failed to open, so the user can try again.
+    pub fn retry(mut self, screen: Box<dyn Screen>) -> Self {
+        self.retry = Some(screen);
+        self
+    }
+
+    /// Returns the recoveries that are possible right now.
+    fn recoveries(&self) -> Vec<Recovery> {
+        if self.notice.is_some() {
+            return Vec::new();
+        }
+        self.error
+            .recoveries()
+            .into_iter()
+            .filter(|recovery| match recovery {
+                Recovery::Retry | Recovery::ChooseOtherFile => self.retry.is_some(),
+                Recovery::RestoreBackup(_) => self.backups_readable,
+                Recovery::Exit => true,
+            })
+            .collect()
+    }
+
+    fn recover(&mut self, recovery: Recovery) -> Result<NavAction> {
+        match recovery {
+            Recovery::Retry => Ok(self.retry.take().map_or(NavAction::Pop, NavAction::Go)),
+            Recovery::ChooseOtherFile => Ok(NavAction::Push(Box::new(DirectoryScreen::new(
+                save_dir(),
+                recursive_scan(),
+            )))),
+            Recovery::RestoreBackup(saves) => {
+                // A backup that was kept since the first write may be much older than the
+                // save, so the user sees how old it is first.
+                let mut question: Vec<String> = saves.iter().map(|save| backup_age(save)).collect();
+                question.push(String::from(tr(Text::RestoreQuestion)));
+                self.restoring = saves;
+                Ok(NavAction::Push(Box::new(ConfirmScreen::new(
+                    question.join(" "),
+                ))))
+            }
+            Recovery::Exit => Ok(NavAction::Exit),
+        }
+    }
+
+    /// Returns the lines of the details view.
+    fn detail_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Code: E{:03}", self.error.code())];
+        for context in self.error.contexts() {
+            lines.push(format!("While: {}", context));
+        }
+        let root = self.error.root();
+        lines.push(format!("Cause: {}", root));
+        lines.push(format!("Debug: {:?}", root));
+        lines
+    }
 }
 
 impl Screen for ErrorScreen {
@@ -11,19 +106,69 @@ impl Screen for ErrorScreen {
     }
 
     fn build(&mut self) {
+        let recoveries = self.recoveries();
+        if self
+            .focus
+            .focused()
+            .is_some_and(|id| id >= FIRST_RECOVERY + recoveries.len())
+        {
+            self.focus.focus(OK);
+        }
+
+        let lines = if self.details {
+            self.detail_lines()
+        } else {
+            vec![self.notice.clone().unwrap_or_else(|| self.error.message())]
+        };
+        let content: Vec<_> = lines
+            .into_iter()
+            .map(|line| text(line).max_width(40))
+            .collect();
+        let toggle = tr(if self.details {
+            Text::Hide
+        } else {
+            Text::Details
+        });
+
+        let mut buttons = Vec::new();
+        for (i, recovery) in recoveries.iter().enumerate() {
+            if i > 0 {
+                buttons.push(text(" ").into_widget());
+            }
+            let label = recovery.label();
+            buttons.push(
+                sizedbox(button(label).focus(&self.focus, FIRST_RECOVERY + i))
+                    .width(label.len() as u16 + 4)
+                    .height(3)
+                    .into_widget(),
+            );
+        }
+
         let ui = border(column(widget_vec![
-            align(padding(text("ERROR")).vertical(1)),
+            align(
+                padding(text(format!(
+                    "{} E{:03}",
+                    tr(Text::ErrorTitle),
+                    self.error.code()
+                )))
+                .vertical(1)
+            ),
             divider('-'),
             text(""),
             flexible(
                 1,
-                align(
-                    border(padding(text(&self.error.to_string()).max_width(40)))
-                        .corners(CORNERS_ROUND),
-                ),
+                align(border(padding(column(content))).corners(CORNERS_ROUND)),
             ),
-            align(sizedbox(button("OK").selected(true)).width(8).height(3)),
-            text(""),
+            align(row(buttons)),
+            align(row(widget_vec![
+                sizedbox(button("OK").focus(&self.focus, OK))
+                    .width(8)
+                    .height(3),
+                text("   "),
+                sizedbox(button(toggle).focus(&self.focus, DETAILS))
+                    .width(11)
+                    .height(3),
+            ])),
             text(""),
         ]))
         .borders(Borders {
@@ -39,16 +184,64 @@ impl Screen for ErrorScreen {
             bottom_right: Some('+'),
         });
 
-        let output = render_ui(ui);
-        console::print(&output);
-        console::flush();
+        render_ui(ui);
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        let input = wait_input();
-        match input {
-            InputKey::Enter => Ok(NavAction::Pop),
-            _ => Ok(NavAction::None),
+    fn on_result(&mut self, result: ScreenResult) -> Result<NavAction> {
+        if let Some(&confirmed) = result.downcast_ref::<bool>() {
+            let saves = core::mem::take(&mut self.restoring);
+            if confirmed {
+                restore_backups(&saves)?;
+                self.notice = Some(trf(Text::BackupRestored, &[&saves.join(", ")]));
+                self.focus.focus(OK);
+            }
+            return Ok(NavAction::None);
+        }
+        if let Ok(chosen) = result.downcast::<DirectoryChosen>() {
+            choose_save_dir(chosen.path, chosen.recursive)?;
+            return self.recover(Recovery::Retry);
+        }
+        Ok(NavAction::None)
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+        match self.focus.handle_input(&input).activated() {
+            Some(OK) => Ok(NavAction::Pop),
+            Some(DETAILS) => {
+                self.details = !self.details;
+                Ok(NavAction::None)
+            }
+            Some(id) => match self.recoveries().get(id - FIRST_RECOVERY) {
+                Some(recovery) => self.recover(recovery.clone()),
+                None => Ok(NavAction::None),
+            },
+            None => Ok(NavAction::None),
+        }
+    }
+}
+
+/// Describes how old the backup of a save is: the play time it was made at and how many
+/// times the game saved since, as far as the save file can still be read.
+fn backup_age(save: &str) -> String {
+    let backup = read_file(&backup_file_path(save)).unwrap_or_default();
+    let play_time = match parse_save(&backup, save) {
+        Ok(loaded) => {
+            let (hours, minutes, _) =
+                trainer_card(&loaded.blocks, trainer_name(&loaded.save)).play_time;
+            format!("{}:{:02}", hours, minutes)
         }
+        Err(_) => String::from("?"),
+    };
+    let saves_since = read_file(&save_file_path(save))
+        .ok()
+        .and_then(|buf| current_save_index(&buf))
+        .zip(current_save_index(&backup))
+        .map(|(current, backup)| current.saturating_sub(backup));
+    match saves_since {
+        Some(count) => trf(Text::BackupAge, &[save, &play_time, &count.to_string()]),
+        None => trf(Text::BackupPlayTime, &[save, &play_time]),
     }
 }
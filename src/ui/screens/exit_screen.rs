@@ -1,6 +1,33 @@
 use crate::prelude::*;
 
-pub struct ExitScreen;
+/// The frames of the exit animation.
+const FRAMES: [Frame; 13] = [
+    (650, PIKACHU, None),
+    (50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (300, PIKACHU, None),
+    (50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (100, PIKACHU, None),
+    (50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (400, PIKACHU, None),
+    (100, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (500, PIKACHU, None),
+    (50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (50, PIKACHU, None),
+    (300, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW)),
+    (50, PIKACHU, None),
+];
+
+pub struct ExitScreen {
+    animation: FrameAnimation,
+}
+
+impl ExitScreen {
+    pub fn new() -> Self {
+        ExitScreen {
+            animation: FrameAnimation::new(&FRAMES),
+        }
+    }
+}
 
 impl Screen for ExitScreen {
     fn init(&mut self) -> Result<NavAction> {
@@ -9,41 +36,20 @@ impl Screen for ExitScreen {
     }
 
     fn build(&mut self) {
-        console::print(&render_ui(image(PIKACHU)));
+        let (_, image_data, color) = self.animation.frame();
+        console::set_color(color.unwrap_or(COLOR_YELLOW));
+        render_ui(image(image_data));
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        sleep(650);
-
-        self.display_frame(50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(300, PIKACHU, None);
-        self.display_frame(50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(100, PIKACHU, None);
-        self.display_frame(50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(400, PIKACHU, None);
-        self.display_frame(100, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(500, PIKACHU, None);
-        self.display_frame(50, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(50, PIKACHU, None);
-        self.display_frame(300, PIKACHU_SPARK, Some(COLOR_LIGHTYELLOW as u8));
-        self.display_frame(50, PIKACHU, None);
-
+    fn tick(&mut self, dt: u32) -> Result<NavAction> {
+        if self.animation.advance(dt) {
+            return Ok(NavAction::None);
+        }
         Ok(NavAction::Pop)
     }
-}
 
-impl ExitScreen {
-    /// Helper function to display a single frame of the exit animation.
-    fn display_frame(&self, sleep_duration: u32, image_data: &str, color: Option<u8>) {
-        console::clear_screen();
-        if let Some(c) = color {
-            console::set_color(c);
-        }
-        console::print(&render_ui(image(image_data)));
-        console::flush();
-        sleep(sleep_duration);
-        if color.is_some() {
-            console::set_color(COLOR_YELLOW);
-        }
+    fn handle_input(&mut self, _input: InputKey) -> Result<NavAction> {
+        // Any key skips the animation.
+        Ok(NavAction::Pop)
     }
 }
@@ -1,18 +1,26 @@
 use crate::prelude::*;
 
+// Focus ids.
+const TRADE: usize = 0;
+const SETTINGS: usize = 1;
+const ABOUT: usize = 2;
+const EXIT: usize = 3;
+
 pub struct MenuScreen {
-    selected: usize,
+    focus: FocusManager,
 }
 
 impl MenuScreen {
     pub fn new() -> Self {
-        MenuScreen { selected: 0 }
+        MenuScreen {
+            focus: FocusManager::new(TRADE),
+        }
     }
 }
 
 impl Screen for MenuScreen {
     fn init(&mut self) -> Result<NavAction> {
-        console::set_color(COLOR_WHITE);
+        console::set_color(settings().color);
         Ok(NavAction::None)
     }
 
@@ -24,15 +32,21 @@ impl Screen for MenuScreen {
             text(""),
             align(
                 padding(column(widget_vec![
-                    text("Trade Pokemon between Ruby, Sapphire,"),
-                    align(text("Emerald, FireRed & LeafGreen!")),
+                    text(tr(Text::MenuTagline1)),
+                    align(text(tr(Text::MenuTagline2))),
                     text(""),
                     text(""),
-                    align(sizedbox(button("Trade").selected(self.selected == 0)).width(16)),
+                    align(row(widget_vec![
+                        sizedbox(button(tr(Text::Trade)).focus(&self.focus, TRADE)).width(16),
+                        text("  "),
+                        sizedbox(button(tr(Text::Settings)).focus(&self.focus, SETTINGS)).width(16),
+                    ])),
                     text(" "),
-                    align(sizedbox(button("About").selected(self.selected == 1)).width(16)),
-                    text(" "),
-                    align(sizedbox(button("Exit").selected(self.selected == 2)).width(16)),
+                    align(row(widget_vec![
+                        sizedbox(button(tr(Text::About)).focus(&self.focus, ABOUT)).width(16),
+                        text("  "),
+                        sizedbox(button(tr(Text::Exit)).focus(&self.focus, EXIT)).width(16),
+                    ])),
                 ]))
                 .left(1)
                 .right(2)
@@ -40,35 +54,18 @@ impl Screen for MenuScreen {
             text(""),
         ]));
 
-        let output = render_ui(ui);
-        console::print(&output);
-        console::flush();
+        render_ui(ui);
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        let input = wait_input();
-        match input {
-            InputKey::Up => {
-                if self.selected > 0 {
-                    self.selected -= 1;
-                }
-                Ok(NavAction::None)
-            }
-            InputKey::Down => {
-                if self.selected < 2 {
-                    self.selected += 1;
-                }
-                Ok(NavAction::None)
-            }
-            InputKey::Enter => {
-                return match self.selected {
-                    0 => Ok(NavAction::Push(Box::new(TradeScreen::new()))),
-                    1 => Ok(NavAction::Push(Box::new(AboutScreen))),
-                    2 => Ok(NavAction::Exit),
-                    _ => panic!("Invalid action."),
-                }
-            }
-            InputKey::Escape => Ok(NavAction::Pop),
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+        match self.focus.handle_input(&input).activated() {
+            Some(TRADE) => Ok(NavAction::Push(Box::new(TradeScreen::new()))),
+            Some(SETTINGS) => Ok(NavAction::Push(Box::new(SettingsScreen::new()))),
+            Some(ABOUT) => Ok(NavAction::Push(Box::new(AboutScreen))),
+            Some(EXIT) => Ok(NavAction::Exit),
             _ => Ok(NavAction::None),
         }
     }
@@ -1,13 +1,31 @@
 pub mod about_screen;
+pub mod confirm_screen;
+pub mod directory_screen;
 pub mod error_screen;
 pub mod exit_screen;
 pub mod menu_screen;
+pub mod nickname_screen;
+pub mod pokedex_screen;
+pub mod pokemon_screen;
+pub mod search_screen;
+pub mod settings_screen;
 pub mod splash_screen;
 pub mod trade_screen;
+pub mod trading_screen;
+pub mod trainer_card_screen;
 
 pub use about_screen::*;
+pub use confirm_screen::*;
+pub use directory_screen::*;
 pub use error_screen::*;
 pub use exit_screen::*;
 pub use menu_screen::*;
+pub use nickname_screen::*;
+pub use pokedex_screen::*;
+pub use pokemon_screen::*;
+pub use search_screen::*;
+pub use settings_screen::*;
 pub use splash_screen::*;
 pub use trade_screen::*;
+pub use trading_screen::*;
+pub use trainer_card_screen::*;
@@ -0,0 +1,168 @@
+use crate::prelude::*;
+use core::cmp::min;
+
+// Focus ids. The keys of the on-screen keyboard are numbered from `FIRST_KEY`.
+const FIELD: usize = 0;
+const CANCEL: usize = 1;
+const SAVE: usize = 2;
+const FIRST_KEY: usize = 10;
+
+/// How long the text cursor stays visible or hidden, in milliseconds.
+const CURSOR_BLINK: u32 = 500;
+
+/// The result of a `NicknameScreen` after the new nickname was saved.
+pub struct Renamed {
+    pub nickname: String,
+}
+
+/// Lets the user rename a party Pokemon, either by typing or with the on-screen keyboard.
+pub struct NicknameScreen {
+    save_name: String,
+    index: usize,
+    species: String,
+    input: TextInput,
+    focus: FocusManager,
+    message: String,
+    /// Time since the last key press, drives the blinking text cursor.
+    blink: u32,
+}
+
+impl NicknameScreen {
+    pub fn new<S: Into<String>>(save_name: S, index: usize) -> Self {
+        NicknameScreen {
+            save_name: save_name.into(),
+            index,
+            species: String::new(),
+            input: TextInput::new("", MAX_NICKNAME_LENGTH).allowed(is_nickname_char),
+            focus: FocusManager::new(FIRST_KEY),
+            message: String::new(),
+            blink: 0,
+        }
+    }
+
+    /// Writes the new nickname into the save file.
+    fn save(&self) -> Result<()> {
+        let mut save = load_save(&self.save_name)?.save;
+        set_nickname(&mut save, self.index, &self.input.value())?;
+        let buf = save
+            .to_bytes()
+            .map_err(|_| AppError::SerializeFailed(self.save_name.clone()))?;
+        write_save(&self.save_name, &buf)
+    }
+}
+
+impl Screen for NicknameScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        let save = load_save(&self.save_name)?;
+        self.species = party_of(&save)
+            .get(self.index)
+            .map(|pokemon| pokemon.label())
+            .ok_or(AppError::PokemonNotFound)?;
+        // Characters that can't be typed can't be saved either, so they are dropped up front.
+        // Otherwise saving the unchanged name would fail.
+        let current = nickname(&save, self.index)?;
+        let name: String = current.chars().filter(|&ch| is_nickname_char(ch)).collect();
+        if name != current {
+            self.message = trf(Text::NicknameFiltered, &[&current]);
+        }
+        self.input = TextInput::new(name, MAX_NICKNAME_LENGTH).allowed(is_nickname_char);
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(tr(Text::Nickname)))).height(3),
+            align(text(format!("{} ({})", self.species, self.save_name))),
+            text(""),
+            align(
+                text_field(&self.input)
+                    .focus(&self.focus, FIELD)
+                    .focused(self.focus.is_focused(FIELD) && (self.blink / CURSOR_BLINK) % 2 == 0)
+            ),
+            text(""),
+            align(keyboard(&NICKNAME_CHARSET).focus(&self.focus, FIRST_KEY)),
+            text(""),
+            align(row(widget_vec![
+                sizedbox(button(tr(Text::Cancel)).focus(&self.focus, CANCEL)).width(12),
+                text("   "),
+                sizedbox(button(tr(Text::Save)).focus(&self.focus, SAVE)).width(12),
+            ])),
+            flexible(1, align(text(&self.message).max_width(40))),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn tick(&mut self, dt: u32) -> Result<NavAction> {
+        self.blink = self.blink.wrapping_add(dt);
+        Ok(NavAction::None)
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        self.message = String::new();
+        // Keep the cursor visible while typing.
+        self.blink = 0;
+
+        // Typing on the physical keyboard always edits the name.
+        if let InputKey::Char(_) | InputKey::Backspace = input {
+            self.input.handle_input(&input);
+            return Ok(NavAction::None);
+        }
+
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+
+        // Left and right move the text cursor while the field has the focus.
+        if self.focus.is_focused(FIELD) && self.input.handle_input(&input) {
+            return Ok(NavAction::None);
+        }
+
+        match self.focus.handle_input(&input) {
+            // Tapping the field moves the cursor to the tapped character.
+            FocusEvent::Tapped(hit) if hit.id == FIELD => {
+                self.input.cursor = min(hit.x as usize, self.input.chars.len());
+            }
+            FocusEvent::Activated(FIELD) => self.focus.focus(SAVE),
+            event => match event.activated() {
+                Some(CANCEL) => return Ok(NavAction::Pop),
+                Some(SAVE) => {
+                    if self.input.chars.is_empty() {
+                        self.message = String::from(tr(Text::EnterNickname));
+                        return Ok(NavAction::None);
+                    }
+                    self.save()?;
+                    return Ok(NavAction::pop_with(Renamed {
+                        nickname: self.input.value(),
+                    }));
+                }
+                Some(id) if id >= FIRST_KEY => {
+                    let key = NICKNAME_CHARSET
+                        .iter()
+                        .flat_map(|row| row.chars())
+                        .nth(id - FIRST_KEY);
+                    if let Some(ch) = key {
+                        if !self.input.insert(ch) {
+                            self.message =
+                                trf(Text::NicknameTooLong, &[&MAX_NICKNAME_LENGTH.to_string()]);
+                        }
+                    }
+                }
+                _ => {}
+            },
+        }
+        Ok(NavAction::None)
+    }
+}
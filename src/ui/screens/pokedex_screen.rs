@@ -0,0 +1,161 @@
+use crate::prelude::*;
+
+// Focus ids.
+const BACK: usize = 0;
+const SWITCH: usize = 1;
+
+/// Rows taken by everything except the list: borders, title, summary and buttons.
+const CHROME_HEIGHT: u16 = 13;
+
+/// Compares the Pokedexes of the two loaded saves. Lists the species that one save has
+/// caught and the other one still needs, and where the first save keeps them.
+pub struct PokedexScreen {
+    saves: (String, String),
+    names: (String, String),
+    /// The gaps of the second save (filled by the first one) and the other way around.
+    gaps: (Vec<DexGap>, Vec<DexGap>),
+    /// Whether the gaps of the first save are shown.
+    reversed: bool,
+    /// The index of the first visible entry.
+    first: usize,
+    focus: FocusManager,
+}
+
+impl PokedexScreen {
+    pub fn new(saves: (String, String), names: (String, String)) -> Self {
+        PokedexScreen {
+            saves,
+            names,
+            gaps: (Vec::new(), Vec::new()),
+            reversed: false,
+            first: 0,
+            focus: FocusManager::new(SWITCH),
+        }
+    }
+
+    /// Returns the gaps that are shown, with the names of the giving and the receiving trainer.
+    fn shown(&self) -> (&[DexGap], &str, &str) {
+        if self.reversed {
+            (&self.gaps.1, &self.names.1, &self.names.0)
+        } else {
+            (&self.gaps.0, &self.names.0, &self.names.1)
+        }
+    }
+
+    fn scroll(&mut self, down: bool) {
+        let (gaps, _, _) = self.shown();
+        let last = gaps.len().saturating_sub(visible_entries());
+        self.first = if down {
+            (self.first + 1).min(last)
+        } else {
+            self.first.saturating_sub(1)
+        };
+    }
+}
+
+impl Screen for PokedexScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        let blocks1 = load_save(&self.saves.0)?.blocks;
+        let blocks2 = load_save(&self.saves.1)?.blocks;
+        self.gaps = (dex_gaps(&blocks1, &blocks2), dex_gaps(&blocks2, &blocks1));
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let (cols, _) = console::size();
+        // Outer border (2) and list border (2).
+        let row_width = cols.saturating_sub(4) as usize;
+        let (gaps, giver, receiver) = self.shown();
+
+        let summary = match gaps.len() {
+            0 => trf(Text::DexNothing, &[giver, receiver]),
+            1 => trf(Text::DexOneGap, &[giver, receiver]),
+            n => trf(Text::DexGaps, &[giver, &n.to_string(), receiver]),
+        };
+        let rows = gaps
+            .iter()
+            .skip(self.first)
+            .take(visible_entries())
+            .map(|gap| {
+                let row = format!(
+                    " #{:03} {:10}  {}",
+                    gap.species,
+                    species_name(gap.species),
+                    sources(&gap.sources)
+                );
+                text(row.chars().take(row_width).collect::<String>())
+            })
+            .collect();
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text("Pokedex"))).height(3),
+            align(text(summary).max_width(cols.saturating_sub(4))),
+            text(""),
+            border(sizedbox(column(rows)).height(visible_entries() as u16)).corners(CORNERS_ROUND),
+            text(""),
+            align(row(widget_vec![
+                sizedbox(button(tr(Text::Back)).focus(&self.focus, BACK)).width(10),
+                text("   "),
+                sizedbox(button(trf(Text::NeededBy, &[giver])).focus(&self.focus, SWITCH))
+                    .width(22),
+            ])),
+            flexible(1, text("")),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        match input {
+            InputKey::Escape => return Ok(NavAction::Pop),
+            // The list isn't focusable, the arrow keys scroll it.
+            InputKey::Up => self.scroll(false),
+            InputKey::Down => self.scroll(true),
+            _ => match self.focus.handle_input(&input).activated() {
+                Some(BACK) => return Ok(NavAction::Pop),
+                Some(SWITCH) => {
+                    self.reversed = !self.reversed;
+                    self.first = 0;
+                }
+                _ => {}
+            },
+        }
+        Ok(NavAction::None)
+    }
+}
+
+/// Returns how many entries of the list fit on the screen.
+fn visible_entries() -> usize {
+    let (_, rows) = console::size();
+    rows.saturating_sub(CHROME_HEIGHT).max(3) as usize
+}
+
+/// Describes where Pokemon of a species are kept, e.g. "Party, Box 3".
+fn sources(locations: &[Location]) -> String {
+    if locations.is_empty() {
+        return String::from(tr(Text::NoneLeft));
+    }
+    let mut places: Vec<String> = Vec::new();
+    for location in locations {
+        let place = match location {
+            Location::Party(_) => String::from(tr(Text::Party)),
+            Location::Box(b, _) => trf(Text::BoxNumber, &[&(b + 1).to_string()]),
+        };
+        if !places.contains(&place) {
+            places.push(place);
+        }
+    }
+    places.join(", ")
+}
@@ -0,0 +1,126 @@
+use crate::prelude::*;
+
+/// The width of the labels next to the sprite.
+const LABEL_WIDTH: usize = 8;
+/// The width of the values next to the sprite.
+const VALUE_WIDTH: usize = 16;
+
+/// Shows the details of a Pokemon next to its sprite.
+pub struct PokemonScreen {
+    pokemon: StoredPokemon,
+    /// A line below the details, e.g. why the Pokemon can't be traded.
+    note: String,
+    focus: FocusManager,
+}
+
+impl PokemonScreen {
+    pub fn new<S: Into<String>>(pokemon: StoredPokemon, note: S) -> Self {
+        PokemonScreen {
+            pokemon,
+            note: note.into(),
+            focus: FocusManager::new(0),
+        }
+    }
+}
+
+impl Screen for PokemonScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let (cols, _) = console::size();
+        let pokemon = &self.pokemon;
+
+        // The game doesn't reveal anything but the trainer of an egg.
+        let mut rows = Vec::new();
+        if !pokemon.is_egg {
+            let type_name = match species_types(pokemon.species) {
+                Some((first, Some(second))) => format!("{}/{}", first.label(), second.label()),
+                Some((first, None)) => String::from(first.label()),
+                None => String::from("-"),
+            };
+            rows.push((tr(Text::CriterionName), pokemon.nickname.clone()));
+            rows.push((tr(Text::CriterionType), type_name));
+            rows.push((
+                tr(Text::CriterionLevel),
+                pokemon.level.map_or(String::from("-"), |l| l.to_string()),
+            ));
+            rows.push((
+                tr(Text::CriterionItem),
+                String::from(item_name(pokemon.held_item).unwrap_or("-")),
+            ));
+            rows.push((
+                tr(Text::CriterionNature),
+                String::from(nature_label(pokemon.nature())),
+            ));
+        }
+        if !pokemon.is_bad_egg {
+            rows.push((
+                tr(Text::CriterionOt),
+                format!("{} ({:05})", pokemon.ot_name, pokemon.ot_id as u16),
+            ));
+        }
+
+        let sprite = pokemon_sprite(pokemon).unwrap_or_default();
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(pokemon.label()))).height(3),
+            align(
+                border(row(widget_vec![
+                    sizedbox(
+                        align(image(sprite))
+                            .horizontal(AlignHorizontal::Center)
+                            .vertical(AlignVertical::Center)
+                    )
+                    .width(SPRITE_WIDTH + 2)
+                    .height(SPRITE_HEIGHT.max(rows.len() as u16)),
+                    divider('|').vertical(),
+                    column(
+                        rows.iter()
+                            .map(|(label, value)| {
+                                text(format!(
+                                    " {:lw$} {:vw$.vw$}",
+                                    label,
+                                    value,
+                                    lw = LABEL_WIDTH,
+                                    vw = VALUE_WIDTH
+                                ))
+                            })
+                            .collect()
+                    ),
+                ]))
+                .corners(CORNERS_ROUND)
+            ),
+            text(""),
+            align(text(&self.note).max_width(cols.saturating_sub(4))),
+            text(""),
+            align(sizedbox(button(tr(Text::Back)).focus(&self.focus, 0)).width(12)),
+            flexible(1, text("")),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+        match self.focus.handle_input(&input).activated() {
+            Some(_) => Ok(NavAction::Pop),
+            None => Ok(NavAction::None),
+        }
+    }
+}
@@ -0,0 +1,406 @@
+use crate::prelude::*;
+
+// Focus ids. The results are numbered from `FIRST_RESULT`.
+const NAME: usize = 0;
+const TYPE: usize = 1;
+const LEVEL: usize = 2;
+const SHINY: usize = 3;
+const ITEM: usize = 4;
+const OT: usize = 5;
+const NATURE: usize = 6;
+const BACK: usize = 7;
+const CLEAR: usize = 8;
+const FIRST_RESULT: usize = 100;
+
+/// Rows taken by everything except the results: borders, title, criteria and buttons.
+const CHROME_HEIGHT: u16 = 20;
+/// The longest name of an item that can be held.
+const ITEM_LENGTH: usize = 12;
+/// The longest name of a trainer.
+const OT_LENGTH: usize = 7;
+/// The width of the criteria names.
+const LABEL_WIDTH: usize = 8;
+/// The width of the criteria values.
+const VALUE_WIDTH: usize = 14;
+
+/// The result of a `SearchScreen` after the user picked a party Pokemon.
+pub struct SearchHit {
+    /// 0 for the first save, 1 for the second one.
+    pub trainer: usize,
+    /// The index of the Pokemon in the party.
+    pub index: usize,
+}
+
+/// Finds Pokemon in the parties and boxes of both saves.
+pub struct SearchScreen {
+    saves: (String, String),
+    names: (String, String),
+    /// The Pokemon of both saves, with the index of their save.
+    pokemon: Vec<(usize, StoredPokemon)>,
+    /// The indices of the Pokemon in `pokemon` that match the filter.
+    results: Vec<usize>,
+    filter: PokemonFilter,
+    name: TextInput,
+    held_item: TextInput,
+    ot: TextInput,
+    /// The index of the first visible result.
+    first: usize,
+    focus: FocusManager,
+}
+
+impl SearchScreen {
+    /// Creates a search that starts with the given text as the name.
+    pub fn new<S: Into<String>>(
+        saves: (String, String),
+        names: (String, String),
+        query: S,
+    ) -> Self {
+        SearchScreen {
+            saves,
+            names,
+            pokemon: Vec::new(),
+            results: Vec::new(),
+            filter: PokemonFilter::default(),
+            name: TextInput::new(query, MAX_NICKNAME_LENGTH),
+            held_item: TextInput::new("", ITEM_LENGTH),
+            ot: TextInput::new("", OT_LENGTH),
+            first: 0,
+            focus: FocusManager::new(NAME),
+        }
+    }
+
+    /// Returns the text field with the given id.
+    fn field(&mut self, id: usize) -> Option<&mut TextInput> {
+        match id {
+            NAME => Some(&mut self.name),
+            ITEM => Some(&mut self.held_item),
+            OT => Some(&mut self.ot),
+            _ => None,
+        }
+    }
+
+    /// Applies the filter and starts over at the first result.
+    fn update(&mut self) {
+        self.filter.name = self.name.value();
+        self.filter.held_item = self.held_item.value();
+        self.filter.ot = self.ot.value();
+        self.results = self
+            .pokemon
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, pokemon))| self.filter.matches(pokemon))
+            .map(|(i, _)| i)
+            .collect();
+        self.first = 0;
+    }
+
+    /// Switches the criterion with the given id to its next (or previous) value.
+    fn cycle(&mut self, id: usize, forward: bool) {
+        match id {
+            TYPE => {
+                let all = PokemonType::ALL;
+                let i = self
+                    .filter
+                    .pokemon_type
+                    .and_then(|t| all.iter().position(|&other| other == t));
+                self.filter.pokemon_type = step(i, all.len(), forward).map(|i| all[i]);
+            }
+            LEVEL => {
+                let i = self
+                    .filter
+                    .level
+                    .and_then(|range| LEVEL_RANGES.iter().position(|&other| other == range));
+                self.filter.level = step(i, LEVEL_RANGES.len(), forward).map(|i| LEVEL_RANGES[i]);
+            }
+            SHINY => {
+                self.filter.shiny = match (self.filter.shiny, forward) {
+                    (None, true) | (Some(true), false) => Some(false),
+                    (Some(false), true) | (None, false) => Some(true),
+                    _ => None,
+                }
+            }
+            NATURE => self.filter.nature = step(self.filter.nature, NATURES.len(), forward),
+            _ => return,
+        }
+        self.update();
+    }
+
+    /// Focuses a result and scrolls the list so it is visible.
+    fn select(&mut self, index: usize) {
+        let visible = visible_results();
+        if index < self.first {
+            self.first = index;
+        } else if index >= self.first + visible {
+            self.first = index + 1 - visible;
+        }
+        self.focus.focus(FIRST_RESULT + index);
+    }
+
+    /// Returns the index of the focused result, if a result has the focus.
+    fn focused_result(&self) -> Option<usize> {
+        self.focus
+            .focused()
+            .and_then(|id| id.checked_sub(FIRST_RESULT))
+            .filter(|&i| i < self.results.len())
+    }
+
+    fn trainer_name(&self, trainer: usize) -> &str {
+        if trainer == 0 {
+            &self.names.0
+        } else {
+            &self.names.1
+        }
+    }
+
+    /// Returns to the trade with a party Pokemon focused. Boxed Pokemon can't be traded,
+    /// their details are shown instead.
+    fn pick(&self, result: usize) -> NavAction {
+        let Some((trainer, pokemon)) = self.results.get(result).map(|&i| self.pokemon[i].clone())
+        else {
+            return NavAction::None;
+        };
+        match pokemon.location {
+            Location::Party(index) => NavAction::pop_with(SearchHit { trainer, index }),
+            Location::Box(b, _) => {
+                let note = trf(
+                    Text::InBox,
+                    &[
+                        &pokemon.label(),
+                        &(b + 1).to_string(),
+                        self.trainer_name(trainer),
+                    ],
+                );
+                NavAction::Push(Box::new(PokemonScreen::new(pokemon, note)))
+            }
+        }
+    }
+}
+
+impl Screen for SearchScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        let blocks1 = load_save(&self.saves.0)?.blocks;
+        let blocks2 = load_save(&self.saves.1)?.blocks;
+        self.pokemon = stored_pokemon(&blocks1)
+            .into_iter()
+            .map(|p| (0, p))
+            .chain(stored_pokemon(&blocks2).into_iter().map(|p| (1, p)))
+            .collect();
+        self.update();
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let (cols, _) = console::size();
+        // Outer border (2), list border (2) and the highlight brackets (4).
+        let row_width = cols.saturating_sub(8) as usize;
+
+        let any = String::from(tr(Text::Any));
+        let type_name = self
+            .filter
+            .pokemon_type
+            .map_or(any.clone(), |t| String::from(t.label()));
+        let level = self.filter.level.map_or(any.clone(), |(min, max)| {
+            trf(Text::PartyLevels, &[&format!("{}-{}", min, max)])
+        });
+        let shiny = match self.filter.shiny {
+            None => any.clone(),
+            Some(true) => String::from(tr(Text::Yes)),
+            Some(false) => String::from(tr(Text::No)),
+        };
+        let nature = self
+            .filter
+            .nature
+            .map_or(any, |i| String::from(nature_label(i)));
+
+        let criterion = |id: usize, label: &str, value: &str| {
+            let focused = self.focus.is_focused(id);
+            let row = match (focused, matches!(id, NAME | ITEM | OT)) {
+                // Text criteria show a cursor, the others can be cycled.
+                (true, true) => format!(
+                    "[ {:lw$}   {:vw$}   ]",
+                    label,
+                    format!("{}_", value),
+                    lw = LABEL_WIDTH,
+                    vw = VALUE_WIDTH
+                ),
+                (true, false) => format!(
+                    "[ {:lw$} < {:vw$} > ]",
+                    label,
+                    value,
+                    lw = LABEL_WIDTH,
+                    vw = VALUE_WIDTH
+                ),
+                (false, _) => format!(
+                    "  {:lw$}   {:vw$}    ",
+                    label,
+                    value,
+                    lw = LABEL_WIDTH,
+                    vw = VALUE_WIDTH
+                ),
+            };
+            tap_target(id, text(row))
+        };
+
+        let results = self
+            .results
+            .iter()
+            .enumerate()
+            .skip(self.first)
+            .take(visible_results())
+            .map(|(i, &p)| {
+                let (trainer, pokemon) = &self.pokemon[p];
+                let place = match pokemon.location {
+                    Location::Party(index) => trf(Text::PartySlot, &[&(index + 1).to_string()]),
+                    Location::Box(b, slot) => trf(
+                        Text::BoxSlot,
+                        &[&(b + 1).to_string(), &(slot + 1).to_string()],
+                    ),
+                };
+                let level = pokemon
+                    .level
+                    .map_or(String::new(), |level| format!("Lv{}", level));
+                let label = format!(
+                    "{:7.7} {:9} {:12} {}",
+                    self.trainer_name(*trainer),
+                    place,
+                    pokemon.label(),
+                    level
+                );
+                let label: String = label.chars().take(row_width).collect();
+                let row = if self.focus.is_focused(FIRST_RESULT + i) {
+                    format!("[ {:w$} ]", label, w = row_width)
+                } else {
+                    format!("  {:w$}  ", label, w = row_width)
+                };
+                tap_target(FIRST_RESULT + i, text(row))
+            })
+            .collect();
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(trf(
+                Text::SearchTitle,
+                &[&self.results.len().to_string()]
+            ))))
+            .height(3),
+            align(
+                border(column(widget_vec![
+                    criterion(NAME, tr(Text::CriterionName), &self.name.value()),
+                    criterion(TYPE, tr(Text::CriterionType), &type_name),
+                    criterion(LEVEL, tr(Text::CriterionLevel), &level),
+                    criterion(SHINY, tr(Text::CriterionShiny), &shiny),
+                    criterion(ITEM, tr(Text::CriterionItem), &self.held_item.value()),
+                    criterion(OT, tr(Text::CriterionOt), &self.ot.value()),
+                    criterion(NATURE, tr(Text::CriterionNature), &nature),
+                ]))
+                .corners(CORNERS_ROUND)
+            ),
+            align(
+                border(sizedbox(column(results)).height(visible_results() as u16))
+                    .corners(CORNERS_ROUND)
+            ),
+            align(row(widget_vec![
+                sizedbox(button(tr(Text::Back)).focus(&self.focus, BACK)).width(10),
+                text("   "),
+                sizedbox(button(tr(Text::Clear)).focus(&self.focus, CLEAR)).width(10),
+            ])),
+            flexible(1, text("")),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+
+        // Typing edits the focused text criterion, or the name if none is focused.
+        if let InputKey::Char(_) | InputKey::Backspace = input {
+            let id = self
+                .focus
+                .focused()
+                .filter(|&id| self.field(id).is_some())
+                .unwrap_or(NAME);
+            self.focus.focus(id);
+            if let Some(field) = self.field(id) {
+                field.handle_input(&input);
+            }
+            self.update();
+            return Ok(NavAction::None);
+        }
+
+        // The results scroll, so moving inside them can't rely on the visible ones alone.
+        if let Some(i) = self.focused_result() {
+            match input {
+                InputKey::Up if i > 0 => {
+                    self.select(i - 1);
+                    return Ok(NavAction::None);
+                }
+                InputKey::Down if i + 1 < self.results.len() => {
+                    self.select(i + 1);
+                    return Ok(NavAction::None);
+                }
+                _ => {}
+            }
+        }
+
+        // Left and right change the focused criterion.
+        match (self.focus.focused(), &input) {
+            (Some(id @ (TYPE | LEVEL | SHINY | NATURE)), InputKey::Left) => {
+                self.cycle(id, false);
+                return Ok(NavAction::None);
+            }
+            (Some(id @ (TYPE | LEVEL | SHINY | NATURE)), InputKey::Right) => {
+                self.cycle(id, true);
+                return Ok(NavAction::None);
+            }
+            _ => {}
+        }
+
+        match self.focus.handle_input(&input).activated() {
+            Some(BACK) => return Ok(NavAction::Pop),
+            Some(CLEAR) => {
+                self.filter = PokemonFilter::default();
+                self.name = TextInput::new("", MAX_NICKNAME_LENGTH);
+                self.held_item = TextInput::new("", ITEM_LENGTH);
+                self.ot = TextInput::new("", OT_LENGTH);
+                self.update();
+            }
+            // Enter in a text criterion jumps to the results.
+            Some(NAME | ITEM | OT) if !self.results.is_empty() => self.select(0),
+            Some(id @ (TYPE | LEVEL | SHINY | NATURE)) => self.cycle(id, true),
+            Some(id) if id >= FIRST_RESULT => return Ok(self.pick(id - FIRST_RESULT)),
+            _ => {}
+        }
+        Ok(NavAction::None)
+    }
+}
+
+/// Returns how many results fit on the screen.
+fn visible_results() -> usize {
+    let (_, rows) = console::size();
+    rows.saturating_sub(CHROME_HEIGHT).max(3) as usize
+}
+
+/// Returns the next (or previous) option in a list of `len` options, where None means "Any"
+/// and comes before the first one.
+fn step(index: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+    match (index, forward) {
+        (None, true) => Some(0),
+        (None, false) => Some(len - 1),
+        (Some(i), true) => (i + 1 < len).then_some(i + 1),
+        (Some(i), false) => i.checked_sub(1),
+    }
+}
@@ -0,0 +1,218 @@
+use crate::prelude::*;
+
+// Focus ids.
+const COLOR: usize = 0;
+const ANIMATIONS: usize = 1;
+const BACKUPS: usize = 2;
+const LANGUAGE: usize = 3;
+const FOLDER: usize = 4;
+const BACK: usize = 5;
+
+/// The width of the setting names in the list.
+const LABEL_WIDTH: usize = 12;
+/// The width of the setting values in the list.
+const VALUE_WIDTH: usize = 15;
+
+/// Shows the settings and lets the user change them. Changes are saved right away.
+pub struct SettingsScreen {
+    focus: FocusManager,
+    message: String,
+}
+
+impl SettingsScreen {
+    pub fn new() -> Self {
+        SettingsScreen {
+            focus: FocusManager::new(COLOR),
+            message: String::new(),
+        }
+    }
+
+    /// Switches the setting with the given id to its next (or previous) value.
+    fn cycle(&mut self, id: usize, forward: bool) {
+        let result = update_settings(|settings| match id {
+            COLOR => {
+                let i = TEXT_COLORS
+                    .iter()
+                    .position(|&(_, c)| c == settings.color)
+                    .unwrap_or(0);
+                settings.color = TEXT_COLORS[step(i, TEXT_COLORS.len(), forward)].1;
+            }
+            ANIMATIONS => {
+                let all = AnimationSpeed::ALL;
+                let i = all
+                    .iter()
+                    .position(|&s| s == settings.animation_speed)
+                    .unwrap_or(0);
+                settings.animation_speed = all[step(i, all.len(), forward)];
+            }
+            BACKUPS => {
+                let all = BackupPolicy::ALL;
+                let i = all
+                    .iter()
+                    .position(|&p| p == settings.backup_policy)
+                    .unwrap_or(0);
+                settings.backup_policy = all[step(i, all.len(), forward)];
+            }
+            LANGUAGE => {
+                // Unset follows the language of the saves.
+                let all: Vec<Option<Language>> = core::iter::once(None)
+                    .chain(Language::ALL.into_iter().map(Some))
+                    .collect();
+                let i = all
+                    .iter()
+                    .position(|&l| l == settings.language)
+                    .unwrap_or(0);
+                settings.language = all[step(i, all.len(), forward)];
+            }
+            _ => {}
+        });
+        if let Err(e) = result {
+            self.message = trf(Text::SettingsNotSaved, &[&e.message()]);
+        }
+        console::set_color(settings().color);
+    }
+}
+
+impl Screen for SettingsScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let settings = settings();
+        let color = color_label(settings.color);
+        let language = match settings.language {
+            Some(language) => String::from(language.name()),
+            None => trf(Text::AutoLanguage, &[detected_language().name()]),
+        };
+        let folder = if recursive_scan() {
+            trf(Text::WithSubfolders, &[&save_dir()])
+        } else {
+            save_dir()
+        };
+
+        let setting = |id: usize, label: &str, value: &str| {
+            let row = if self.focus.is_focused(id) {
+                format!(
+                    "[ {:lw$} < {:vw$} > ]",
+                    label,
+                    value,
+                    lw = LABEL_WIDTH,
+                    vw = VALUE_WIDTH
+                )
+            } else {
+                format!(
+                    "  {:lw$}   {:vw$}    ",
+                    label,
+                    value,
+                    lw = LABEL_WIDTH,
+                    vw = VALUE_WIDTH
+                )
+            };
+            tap_target(id, text(row))
+        };
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(tr(Text::Settings)))).height(3),
+            align(
+                border(column(widget_vec![
+                    setting(COLOR, tr(Text::TextColor), color),
+                    setting(
+                        ANIMATIONS,
+                        tr(Text::Animations),
+                        settings.animation_speed.label()
+                    ),
+                    setting(BACKUPS, tr(Text::Backups), settings.backup_policy.label()),
+                    setting(LANGUAGE, tr(Text::LanguageSetting), &language),
+                ]))
+                .corners(CORNERS_ROUND)
+            ),
+            text(""),
+            align(text(tr(Text::SaveFolder))),
+            align(text(folder).max_width(40)),
+            text(""),
+            align(row(widget_vec![
+                sizedbox(button(tr(Text::Back)).focus(&self.focus, BACK)).width(12),
+                text("   "),
+                sizedbox(button(tr(Text::ChangeFolder)).focus(&self.focus, FOLDER)).width(17),
+            ])),
+            flexible(1, align(text(&self.message).max_width(40))),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn on_result(&mut self, result: ScreenResult) -> Result<NavAction> {
+        if let Ok(chosen) = result.downcast::<DirectoryChosen>() {
+            if let Err(e) = choose_save_dir(chosen.path, chosen.recursive) {
+                self.message = trf(Text::SettingsNotSaved, &[&e.message()]);
+            }
+        }
+        Ok(NavAction::None)
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        self.message = String::new();
+
+        // Left and right change the focused setting.
+        let focused = self.focus.focused().filter(|&id| id < FOLDER);
+        match (focused, &input) {
+            (_, InputKey::Escape) => return Ok(NavAction::Pop),
+            (Some(id), InputKey::Left) => {
+                self.cycle(id, false);
+                return Ok(NavAction::None);
+            }
+            (Some(id), InputKey::Right) => {
+                self.cycle(id, true);
+                return Ok(NavAction::None);
+            }
+            _ => {}
+        }
+
+        match self.focus.handle_input(&input).activated() {
+            Some(BACK) => return Ok(NavAction::Pop),
+            Some(FOLDER) => {
+                return Ok(NavAction::Push(Box::new(DirectoryScreen::new(
+                    save_dir(),
+                    recursive_scan(),
+                ))))
+            }
+            Some(id) => self.cycle(id, true),
+            None => {}
+        }
+        Ok(NavAction::None)
+    }
+}
+
+/// Returns the name of a text color in the current language.
+fn color_label(color: u8) -> &'static str {
+    tr(match color {
+        COLOR_WHITE => Text::ColorWhite,
+        COLOR_LIGHTGREEN => Text::ColorGreen,
+        COLOR_LIGHTCYAN => Text::ColorCyan,
+        COLOR_LIGHTYELLOW => Text::ColorYellow,
+        COLOR_LIGHTMAGENTA => Text::ColorMagenta,
+        _ => Text::ColorCustom,
+    })
+}
+
+/// Returns the next (or previous) index in a list of `len` options, wrapping around.
+fn step(index: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (index + 1) % len
+    } else {
+        (index + len - 1) % len
+    }
+}
@@ -1,6 +1,33 @@
 use crate::prelude::*;
 
-pub struct SplashScreen;
+/// The frames of the splash animation.
+const FRAMES: [Frame; 13] = [
+    (650, MEW, None),
+    (50, MEW_FLASH, None),
+    (300, MEW, None),
+    (50, MEW_FLASH, None),
+    (100, MEW, None),
+    (50, MEW_FLASH, None),
+    (400, MEW, None),
+    (100, MEW_FLASH_SPARKLE, Some(COLOR_LIGHTMAGENTA)),
+    (500, MEW, None),
+    (50, MEW, None),
+    (50, MEW_FLASH_SPARKLE, Some(COLOR_LIGHTMAGENTA)),
+    (300, MEW_FLASH_FULL, Some(COLOR_LIGHTMAGENTA)),
+    (50, MEW, None),
+];
+
+pub struct SplashScreen {
+    animation: FrameAnimation,
+}
+
+impl SplashScreen {
+    pub fn new() -> Self {
+        SplashScreen {
+            animation: FrameAnimation::new(&FRAMES),
+        }
+    }
+}
 
 impl Screen for SplashScreen {
     fn init(&mut self) -> Result<NavAction> {
@@ -9,42 +36,20 @@ impl Screen for SplashScreen {
     }
 
     fn build(&mut self) {
-        console::print(&render_ui(image(MEW)));
+        let (_, image_data, color) = self.animation.frame();
+        console::set_color(color.unwrap_or(COLOR_MAGENTA));
+        render_ui(image(image_data));
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        sleep(650);
-
-        self.display_frame(50, MEW_FLASH, None);
-        self.display_frame(300, MEW, None);
-        self.display_frame(50, MEW_FLASH, None);
-        self.display_frame(100, MEW, None);
-        self.display_frame(50, MEW_FLASH, None);
-        self.display_frame(400, MEW, None);
-        self.display_frame(100, MEW_FLASH_SPARKLE, Some(COLOR_LIGHTMAGENTA as u8));
-        self.display_frame(500, MEW, None);
-        self.display_frame(50, MEW, None);
-        self.display_frame(50, MEW_FLASH_SPARKLE, Some(COLOR_LIGHTMAGENTA as u8));
-        self.display_frame(300, MEW_FLASH_FULL, Some(COLOR_LIGHTMAGENTA as u8));
-        self.display_frame(50, MEW, None);
-
+    fn tick(&mut self, dt: u32) -> Result<NavAction> {
+        if self.animation.advance(dt) {
+            return Ok(NavAction::None);
+        }
         Ok(NavAction::Go(Box::new(MenuScreen::new())))
     }
-}
 
-impl SplashScreen {
-    /// Helper function to display a single frame of the splash animation.
-    fn display_frame(&self, sleep_duration: u32, image_data: &str, color: Option<u8>) {
-        console::clear_screen();
-        if let Some(c) = color {
-            console::set_color(c);
-        }
-        console::print(&render_ui(image(image_data)));
-        console::flush();
-        sleep(sleep_duration);
-        if color.is_some() {
-            // Reset the color back to default after a colored frame.
-            console::set_color(COLOR_MAGENTA);
-        }
+    fn handle_input(&mut self, _input: InputKey) -> Result<NavAction> {
+        // Any key skips the animation.
+        Ok(NavAction::Go(Box::new(MenuScreen::new())))
     }
 }
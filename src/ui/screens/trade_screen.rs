@@ -1,8 +1,16 @@
-use pkmn_savedata::gba::{GbaSave, LanguageGBA};
-
 use crate::prelude::*;
-use crate::ui::rendering::IntoWidget;
-use core::cmp::min;
+use crate::ui::widgets::column::Column;
+
+// Focus ids. The trainer names open their trainer cards, the party rows are numbered from `PARTY1` and `PARTY2`.
+const BACK: usize = 0;
+const RENAME: usize = 1;
+const TRADE: usize = 2;
+const CARD1: usize = 3;
+const CARD2: usize = 4;
+const POKEDEX: usize = 5;
+const SEARCH: usize = 6;
+const PARTY1: usize = 100;
+const PARTY2: usize = 200;
 
 #[derive(PartialEq, Eq, Clone)]
 enum Trainer {
@@ -10,26 +18,17 @@ enum Trainer {
     Trainer2,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-enum Action {
-    Back,
-    Trade,
-}
-
-#[derive(Clone)]
 pub struct TradeScreen {
     saves: Option<(String, String)>,
     selected_pokemon_trainer1: Option<usize>,
     selected_pokemon_trainer2: Option<usize>,
-    pokemon_list_trainer1: Vec<String>,
-    pokemon_list_trainer2: Vec<String>,
+    pokemon_list_trainer1: Vec<StoredPokemon>,
+    pokemon_list_trainer2: Vec<StoredPokemon>,
     current_trainer: Trainer,
     trainer_name1: String,
     trainer_name2: String,
-    cursor_position: usize,
-    selected_action: Option<Action>,
+    focus: FocusManager,
     message: String,
-    progress: Option<f32>,
 }
 
 impl TradeScreen {
@@ -38,177 +37,295 @@ impl TradeScreen {
             saves: None,
             selected_pokemon_trainer1: None,
             selected_pokemon_trainer2: None,
-            pokemon_list_trainer1: vec![
-                "Bulbasaur".to_string(),
-                "Charmander".to_string(),
-                "Squirtle".to_string(),
-                "Pikachu".to_string(),
-            ],
-            pokemon_list_trainer2: vec![
-                "Eevee".to_string(),
-                "Vaporeon".to_string(),
-                "Jolteon".to_string(),
-                "Flareon".to_string(),
-            ],
+            pokemon_list_trainer1: Vec::new(),
+            pokemon_list_trainer2: Vec::new(),
             trainer_name1: String::new(),
             trainer_name2: String::new(),
             current_trainer: Trainer::Trainer1,
-            cursor_position: 0,
-            selected_action: None,
+            focus: FocusManager::new(PARTY1),
             message: String::new(),
-            progress: None,
         }
     }
 
-    /// Animates the progress value from `from` to `to` over `duration_ms` milliseconds,
-    /// using a cubic ease‑out curve (ease(t)=1 - (1-t)³). During the animation, the screen
-    /// is cleared, the UI is rebuilt, and the console is flushed.
-    pub fn animate_progress(&mut self, from: f32, to: f32, duration_ms: u32) {
-        #[cfg(feature = "calculator-build")]
-        // Frame times on a calculator are nowhere near 60 FPS.
-        // If we picked a higher number than achievable, our calculations will be off
-        // and the animation takes longer than intended.
-        const FPS: u32 = 30;
+    /// Returns the names of the saves that `init` loaded.
+    fn loaded_saves(&self) -> Result<(String, String)> {
+        self.saves.clone().ok_or(AppError::SavesNotLoaded)
+    }
+
+    /// Opens the nickname editor for the selected Pokemon,
+    /// preferring the current trainer's side.
+    fn rename(&mut self) -> Result<NavAction> {
+        let saves = self.loaded_saves()?;
+        let selection = match self.current_trainer {
+            Trainer::Trainer1 => [
+                (
+                    saves.0,
+                    self.selected_pokemon_trainer1,
+                    &self.pokemon_list_trainer1,
+                ),
+                (
+                    saves.1,
+                    self.selected_pokemon_trainer2,
+                    &self.pokemon_list_trainer2,
+                ),
+            ],
+            Trainer::Trainer2 => [
+                (
+                    saves.1,
+                    self.selected_pokemon_trainer2,
+                    &self.pokemon_list_trainer2,
+                ),
+                (
+                    saves.0,
+                    self.selected_pokemon_trainer1,
+                    &self.pokemon_list_trainer1,
+                ),
+            ],
+        };
+        match selection
+            .into_iter()
+            .find_map(|(save, p, party)| p.map(|p| (save, p, party)))
+        {
+            Some((_, p, party)) if party.get(p).is_some_and(|pokemon| pokemon.is_egg) => {
+                self.message = String::from(tr(Text::EggRename));
+                Ok(NavAction::None)
+            }
+            Some((save, p, _)) => Ok(NavAction::Push(Box::new(NicknameScreen::new(save, p)))),
+            None => {
+                self.message = String::from(tr(Text::SelectToRename));
+                Ok(NavAction::None)
+            }
+        }
+    }
 
-        #[cfg(feature = "desktop")]
-        const FPS: u32 = 60;
+    /// Opens the trainer card of one of the loaded saves.
+    fn show_card(&mut self, trainer: Trainer) -> Result<NavAction> {
+        let saves = self.loaded_saves()?;
+        let save = match trainer {
+            Trainer::Trainer1 => saves.0,
+            Trainer::Trainer2 => saves.1,
+        };
+        Ok(NavAction::Push(Box::new(TrainerCardScreen::new(save))))
+    }
 
-        let frame_time: u32 = 1000 / FPS;
-        // Calculate the total number of frames (ignoring any remainder).
-        let total_frames = duration_ms / frame_time;
+    /// Opens the comparison of the Pokedexes of both saves.
+    fn compare_pokedex(&mut self) -> Result<NavAction> {
+        let saves = self.loaded_saves()?;
+        let names = (self.trainer_name1.clone(), self.trainer_name2.clone());
+        Ok(NavAction::Push(Box::new(PokedexScreen::new(saves, names))))
+    }
 
-        for frame in 0..total_frames {
-            // Normalized time in [0,1].
-            let t = (frame as f32) / (total_frames as f32);
-            // Cubic ease-out function.
-            let ease = 1.0 - (1.0 - t).powi(3);
-            self.progress = Some(from + (to - from) * ease);
-            console::clear_screen();
-            self.build();
-            console::flush();
-            sleep(frame_time);
+    /// Opens the search across both saves, starting with the given name.
+    fn search(&mut self, query: String) -> Result<NavAction> {
+        let saves = self.loaded_saves()?;
+        let names = (self.trainer_name1.clone(), self.trainer_name2.clone());
+        Ok(NavAction::Push(Box::new(SearchScreen::new(
+            saves, names, query,
+        ))))
+    }
+
+    /// Returns the Pokemon whose sprite is shown below the trainer's party:
+    /// the focused one, or else the selected one.
+    fn previewed(&self, trainer: Trainer) -> Option<&StoredPokemon> {
+        let (list, first_id, selected) = match trainer {
+            Trainer::Trainer1 => (
+                &self.pokemon_list_trainer1,
+                PARTY1,
+                self.selected_pokemon_trainer1,
+            ),
+            Trainer::Trainer2 => (
+                &self.pokemon_list_trainer2,
+                PARTY2,
+                self.selected_pokemon_trainer2,
+            ),
+        };
+        let index = match self.focus.focused() {
+            Some(id) if (first_id..first_id + list.len()).contains(&id) => Some(id - first_id),
+            _ => selected,
+        };
+        index.and_then(|index| list.get(index))
+    }
+
+    /// Lists a trainer's party. The rows get the focus ids from `first_id` on and the
+    /// selected Pokemon is marked with arrows.
+    fn party_list(
+        &self,
+        party: &[StoredPokemon],
+        first_id: usize,
+        selection: Option<usize>,
+        name_width: usize,
+    ) -> Column {
+        column(
+            party
+                .iter()
+                .enumerate()
+                .map(|(i, pokemon)| {
+                    let name: String = pokemon.label().chars().take(name_width).collect();
+                    let highlighted = self.focus.is_focused(first_id + i);
+                    let selected = selection == Some(i);
+                    let pokemon_name = if selected && highlighted {
+                        format!(" [>{:w$}<] ", name, w = name_width)
+                    } else if selected {
+                        format!("  >{:w$}<  ", name, w = name_width)
+                    } else if highlighted {
+                        format!(" [ {:w$} ] ", name, w = name_width)
+                    } else {
+                        format!("   {:w$}   ", name, w = name_width)
+                    };
+
+                    tap_target(first_id + i, text(pokemon_name))
+                })
+                .collect(),
+        )
+    }
+
+    /// Asks the user to confirm the trade of the two selected Pokemon.
+    fn confirm_trade(&mut self) -> Result<NavAction> {
+        if let (Some(p1), Some(p2)) = (
+            self.selected_pokemon_trainer1,
+            self.selected_pokemon_trainer2,
+        ) {
+            let (pokemon1, pokemon2) = (
+                &self.pokemon_list_trainer1[p1],
+                &self.pokemon_list_trainer2[p2],
+            );
+            if pokemon1.is_bad_egg || pokemon2.is_bad_egg {
+                self.message = AppError::BadEgg.message();
+                return Ok(NavAction::None);
+            }
+            let question = trf(Text::TradeQuestion, &[&pokemon1.label(), &pokemon2.label()]);
+            Ok(NavAction::Push(Box::new(
+                ConfirmScreen::new(question).labels(tr(Text::TradeButton), tr(Text::Cancel)),
+            )))
+        } else {
+            self.message = String::from(tr(Text::SelectEach));
+            Ok(NavAction::None)
         }
-        // Ensure the progress value is exactly 'to' at the end.
-        self.progress = Some(to);
-        console::clear_screen();
-        self.build();
-        console::flush();
+    }
+
+    /// Trades the two selected Pokemon while the trade animation plays.
+    fn trade(&mut self, p1: usize, p2: usize) -> Result<NavAction> {
+        let saves = self.loaded_saves()?;
+        let pokemon = (
+            self.pokemon_list_trainer1
+                .get(p1)
+                .cloned()
+                .ok_or(AppError::PokemonNotFound)?,
+            self.pokemon_list_trainer2
+                .get(p2)
+                .cloned()
+                .ok_or(AppError::PokemonNotFound)?,
+        );
+        let names = (self.trainer_name1.clone(), self.trainer_name2.clone());
+        Ok(NavAction::Push(Box::new(TradingScreen::new(
+            saves,
+            names,
+            (p1, p2),
+            pokemon,
+        ))))
     }
 }
 
 impl Screen for TradeScreen {
     fn init(&mut self) -> Result<NavAction> {
-        let dir = get_dir();
-        let files = read_dir(&dir)?;
-
-        let (name1, name2) = find_first_two_sav_tns(&files)?;
+        let (name1, name2) = find_trade_saves()?;
 
         self.saves = Some((name1.clone(), name2.clone()));
 
         let save1 = load_save(&name1)?;
         let save2 = load_save(&name2)?;
 
-        self.trainer_name1 = trainer_name(&save1);
-        self.trainer_name2 = trainer_name(&save2);
+        self.trainer_name1 = trainer_name(&save1.save);
+        self.trainer_name2 = trainer_name(&save2.save);
 
-        self.pokemon_list_trainer1 = party_names(&save1)?;
-        self.pokemon_list_trainer2 = party_names(&save2)?;
+        self.pokemon_list_trainer1 = party_of(&save1);
+        self.pokemon_list_trainer2 = party_of(&save2);
 
         Ok(NavAction::None)
     }
 
     fn build(&mut self) {
-        let data = self.clone();
+        let column_width = column_width();
+        // The list entries are decorated with 3 characters on each side.
+        let name_width = column_width as usize - 6;
+        let sprite1 = self
+            .previewed(Trainer::Trainer1)
+            .and_then(pokemon_sprite)
+            .unwrap_or_default();
+        let sprite2 = self
+            .previewed(Trainer::Trainer2)
+            .and_then(pokemon_sprite)
+            .unwrap_or_default();
 
         let ui = border(column(widget_vec![
             sizedbox(
-                align(text("Trading"))
+                align(text(tr(Text::Trading)))
                     .horizontal(AlignHorizontal::Center)
                     .vertical(AlignVertical::Center),
             )
             .height(3),
             align(row(widget_vec![border(row(widget_vec![
                 sizedbox(column(widget_vec![
-                    align(text(&format!("{}", self.trainer_name1)))
-                        .horizontal(AlignHorizontal::Center),
+                    align(tap_target(
+                        CARD1,
+                        text(header(&self.trainer_name1, self.focus.is_focused(CARD1)))
+                    ))
+                    .horizontal(AlignHorizontal::Center),
                     divider('-'),
-                    column(
-                        self.pokemon_list_trainer1
-                            .iter()
-                            .enumerate()
-                            .map(|(i, name)| {
-                                let highlighted = self.current_trainer == Trainer::Trainer1
-                                    && self.cursor_position == i
-                                    && self.selected_action.is_none();
-                                let selected = self.selected_pokemon_trainer1 == Some(i);
-                                let pokemon_name = if selected && highlighted {
-                                    format!(" [>{:10}<] ", name)
-                                } else if selected {
-                                    format!("  >{:10}<  ", name)
-                                } else if highlighted {
-                                    format!(" [ {:10} ] ", name)
-                                } else {
-                                    format!("   {:10}   ", name)
-                                };
-
-                                text(pokemon_name)
-                            })
-                            .collect(),
+                    self.party_list(
+                        &self.pokemon_list_trainer1,
+                        PARTY1,
+                        self.selected_pokemon_trainer1,
+                        name_width
                     ),
+                    divider('-'),
+                    sizedbox(
+                        align(image(sprite1))
+                            .horizontal(AlignHorizontal::Center)
+                            .vertical(AlignVertical::Center)
+                    )
+                    .height(SPRITE_HEIGHT),
                 ]))
-                .width(16),
+                .width(column_width),
                 divider('|').vertical(),
                 sizedbox(column(widget_vec![
-                    align(text(&format!("{}", self.trainer_name2)))
-                        .horizontal(AlignHorizontal::Center),
+                    align(tap_target(
+                        CARD2,
+                        text(header(&self.trainer_name2, self.focus.is_focused(CARD2)))
+                    ))
+                    .horizontal(AlignHorizontal::Center),
                     divider('-'),
-                    column(
-                        self.pokemon_list_trainer2
-                            .iter()
-                            .enumerate()
-                            .map(|(i, name)| {
-                                let highlighted = self.current_trainer == Trainer::Trainer2
-                                    && self.cursor_position == i
-                                    && self.selected_action.is_none();
-                                let selected = self.selected_pokemon_trainer2 == Some(i);
-                                let pokemon_name = if selected && highlighted {
-                                    format!(" [>{:10}<] ", name)
-                                } else if selected {
-                                    format!("  >{:10}<  ", name)
-                                } else if highlighted {
-                                    format!(" [ {:10} ] ", name)
-                                } else {
-                                    format!("   {:10}   ", name)
-                                };
-
-                                text(pokemon_name)
-                            })
-                            .collect(),
+                    self.party_list(
+                        &self.pokemon_list_trainer2,
+                        PARTY2,
+                        self.selected_pokemon_trainer2,
+                        name_width
                     ),
+                    divider('-'),
+                    sizedbox(
+                        align(image(sprite2))
+                            .horizontal(AlignHorizontal::Center)
+                            .vertical(AlignVertical::Center)
+                    )
+                    .height(SPRITE_HEIGHT),
                 ]))
-                .width(16),
+                .width(column_width),
             ]))
             .corners(CORNERS_ROUND),]))
             .horizontal(AlignHorizontal::Center),
             text(""),
             align(row(widget_vec![
-                sizedbox(button("Back").selected(self.selected_action == Some(Action::Back)))
-                    .width(12),
-                text("   "),
-                sizedbox(button("Trade").selected(self.selected_action == Some(Action::Trade)))
-                    .width(12),
+                sizedbox(button(tr(Text::Back)).focus(&self.focus, BACK)).width(9),
+                text(" "),
+                sizedbox(button(tr(Text::SearchButton)).focus(&self.focus, SEARCH)).width(9),
+                text(" "),
+                sizedbox(button(tr(Text::PokedexButton)).focus(&self.focus, POKEDEX)).width(9),
+                text(" "),
+                sizedbox(button(tr(Text::RenameButton)).focus(&self.focus, RENAME)).width(9),
+                text(" "),
+                sizedbox(button(tr(Text::TradeButton)).focus(&self.focus, TRADE)).width(9),
             ]),),
             flexible(1, align(text(&self.message)),),
-            padding(builder(move || {
-                if let Some(progress) = data.progress {
-                    return border(progress_bar(progress, '/', '|', ' '))
-                        .corners(CORNERS_ROUND)
-                        .into_widget();
-                }
-                return text("").into_widget();
-            }))
-            .horizontal(2)
-            .bottom(1),
         ]))
         .borders(Borders {
             left: Some('|'),
@@ -223,184 +340,95 @@ impl Screen for TradeScreen {
             bottom_right: Some('+'),
         });
 
-        let output = render_ui(ui);
-        console::print(&output);
-        console::flush();
+        render_ui(ui);
     }
 
-    fn handle_input(&mut self) -> Result<NavAction> {
-        let input = wait_input();
-        self.message = String::new();
-
-        match input {
-            InputKey::Up => {
-                if self.selected_action.is_some() {
-                    self.selected_action = None;
-                } else if self.cursor_position > 0 {
-                    self.cursor_position -= 1;
-                }
-            }
-            InputKey::Down => {
-                if self.selected_action.is_none()
-                    && ((self.current_trainer == Trainer::Trainer1
-                        && self.cursor_position == self.pokemon_list_trainer1.len() - 1)
-                        || (self.current_trainer == Trainer::Trainer2
-                            && self.cursor_position == self.pokemon_list_trainer2.len() - 1))
-                {
-                    self.selected_action = Some(Action::Back);
-                } else {
-                    let max_index = match self.current_trainer {
-                        Trainer::Trainer1 => self.pokemon_list_trainer1.len() - 1,
-                        Trainer::Trainer2 => self.pokemon_list_trainer2.len() - 1,
-                    };
-                    if self.cursor_position < max_index {
-                        self.cursor_position += 1;
-                    }
-                }
-            }
-            InputKey::Left => {
-                if self.selected_action == Some(Action::Trade) {
-                    self.selected_action = Some(Action::Back);
-                } else {
-                    self.current_trainer = Trainer::Trainer1;
-                    self.cursor_position =
-                        min(self.cursor_position, self.pokemon_list_trainer1.len() - 1);
-                    self.selected_action = None;
-                }
+    fn on_result(&mut self, result: ScreenResult) -> Result<NavAction> {
+        if let Some(traded) = result.downcast_ref::<Traded>() {
+            if traded.leave {
+                return Ok(NavAction::Pop);
             }
-            InputKey::Right => {
-                if self.selected_action == Some(Action::Back) {
-                    self.selected_action = Some(Action::Trade);
-                } else {
-                    self.current_trainer = Trainer::Trainer2;
-                    self.cursor_position =
-                        min(self.cursor_position, self.pokemon_list_trainer2.len() - 1);
-                    self.selected_action = None;
-                }
+            // Reload the parties, they changed with the trade.
+            return Ok(NavAction::Go(Box::new(TradeScreen::new())));
+        } else if let Some(renamed) = result.downcast_ref::<Renamed>() {
+            self.message = trf(Text::Renamed, &[&renamed.nickname]);
+        } else if let Some(hit) = result.downcast_ref::<SearchHit>() {
+            let (first_id, trainer) = if hit.trainer == 0 {
+                (PARTY1, Trainer::Trainer1)
+            } else {
+                (PARTY2, Trainer::Trainer2)
+            };
+            self.focus.focus(first_id + hit.index);
+            self.current_trainer = trainer;
+        } else if let Some(&confirmed) = result.downcast_ref::<bool>() {
+            if let (true, Some(p1), Some(p2)) = (
+                confirmed,
+                self.selected_pokemon_trainer1,
+                self.selected_pokemon_trainer2,
+            ) {
+                return self.trade(p1, p2);
             }
-            InputKey::Enter => {
-                if let Some(action) = &self.selected_action {
-                    return match action {
-                        Action::Back => Ok(NavAction::Pop),
-                        Action::Trade => {
-                            if let (Some(p1), Some(p2)) = (
-                                self.selected_pokemon_trainer1,
-                                self.selected_pokemon_trainer2,
-                            ) {
-                                let animation_targets = [0.32, 0.5, 0.68, 1.0];
-
-                                self.message = String::from("Trading...");
-                                self.progress = Some(0.0);
-                                console::clear_screen();
-                                self.build();
-                                console::flush();
-
-                                self.animate_progress(0.0, animation_targets[0], 750);
-
-                                let saves = self
-                                    .saves
-                                    .clone()
-                                    .ok_or(AppError::Custom("The save files that were loaded into memory are no longer there. wtf?".into()))?;
-
-                                let mut save1 = load_save(&saves.0)?;
-                                let mut save2 = load_save(&saves.1)?;
-
-                                trade_pokemon(&mut save1, &mut save2, p1, p2)?;
-
-                                self.animate_progress(
-                                    animation_targets[0],
-                                    animation_targets[1],
-                                    750,
-                                );
-
-                                let buf1 = save1.to_bytes().map_err(|_| {
-                                    AppError::Custom(format!(
-                                        "Failed to serialize save file {}. Don't worry, nothing has been written yet.",
-                                        saves.0
-                                    ))
-                                })?;
-                                let buf2 = save2.to_bytes().map_err(|_| {
-                                    AppError::Custom(format!(
-                                        "Failed to serialize save file {}. Don't worry, nothing has been written yet.",
-                                        saves.1
-                                    ))
-                                })?;
-
-                                self.animate_progress(
-                                    animation_targets[1],
-                                    animation_targets[2],
-                                    750,
-                                );
-
-                                write_save(&saves.0, &buf1)?;
-                                write_save(&saves.1, &buf2)?;
+        }
+        Ok(NavAction::None)
+    }
 
-                                self.animate_progress(
-                                    animation_targets[2],
-                                    animation_targets[3],
-                                    750,
-                                );
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        self.message = String::new();
 
-                                sleep(100);
-                                self.message = String::from("Trade successful!");
-                                console::clear_screen();
-                                self.build();
-                                console::flush();
+        match input {
+            InputKey::Escape => return Ok(NavAction::Pop),
+            // Typing starts a search for the typed name.
+            InputKey::Char(ch) if ch.is_alphanumeric() => return self.search(ch.to_string()),
+            _ => {}
+        }
 
-                                let input = wait_input();
-                                match input {
-                                    InputKey::Escape => Ok(NavAction::Pop),
-                                    _ => Ok(NavAction::Go(Box::new(TradeScreen::new()))),
-                                }
-                            } else {
-                                self.message =
-                                    String::from("Please select a pokemon from each trainer.");
-                                Ok(NavAction::None)
-                            }
-                        }
-                    };
-                }
+        let event = self.focus.handle_input(&input);
+        // Remember the side of the focused Pokemon, renaming prefers it.
+        match self.focus.focused() {
+            Some(id) if id >= PARTY2 => self.current_trainer = Trainer::Trainer2,
+            Some(id) if id >= PARTY1 => self.current_trainer = Trainer::Trainer1,
+            _ => {}
+        }
 
-                // Toggle Pokémon selection for the current trainer.
-                match self.current_trainer {
-                    Trainer::Trainer1 => {
-                        if self.selected_pokemon_trainer1 == Some(self.cursor_position) {
-                            self.selected_pokemon_trainer1 = None;
-                        } else {
-                            self.selected_pokemon_trainer1 = Some(self.cursor_position);
-                        }
-                    }
-                    Trainer::Trainer2 => {
-                        if self.selected_pokemon_trainer2 == Some(self.cursor_position) {
-                            self.selected_pokemon_trainer2 = None;
-                        } else {
-                            self.selected_pokemon_trainer2 = Some(self.cursor_position);
-                        }
-                    }
-                }
-            }
-            InputKey::Escape => {
-                return Ok(NavAction::Pop);
-            }
+        match event.activated() {
+            Some(BACK) => return Ok(NavAction::Pop),
+            Some(RENAME) => return self.rename(),
+            Some(TRADE) => return self.confirm_trade(),
+            Some(POKEDEX) => return self.compare_pokedex(),
+            Some(SEARCH) => return self.search(String::new()),
+            Some(CARD1) => return self.show_card(Trainer::Trainer1),
+            Some(CARD2) => return self.show_card(Trainer::Trainer2),
+            // Toggle the Pokemon's selection.
+            Some(id) if id >= PARTY2 => toggle(&mut self.selected_pokemon_trainer2, id - PARTY2),
+            Some(id) if id >= PARTY1 => toggle(&mut self.selected_pokemon_trainer1, id - PARTY1),
+            _ => {}
         }
         Ok(NavAction::None)
     }
 }
 
-fn load_save(name: &str) -> Result<GbaSave> {
-    let buf = read_file(&save_file_path(name))
-        .map_err(|e| AppError::Custom(format!("Failed to read save file {}: {:?}", name, e)))?;
-    GbaSave::from_bytes(&buf, LanguageGBA::English)
-        .map_err(|_| AppError::Custom(format!("Failed to parse save file {}.", name)))
+/// Selects the given index, or deselects it if it was already selected.
+fn toggle(selection: &mut Option<usize>, index: usize) {
+    if *selection == Some(index) {
+        *selection = None;
+    } else {
+        *selection = Some(index);
+    }
+}
+
+/// Formats a trainer's name above their party, with brackets if it has the focus.
+fn header(name: &str, focused: bool) -> String {
+    if focused {
+        format!("[ {} ]", name)
+    } else {
+        format!("  {}  ", name)
+    }
 }
 
-fn write_save(name: &str, buf: &[u8]) -> Result<()> {
-    let path = save_file_path(name);
-    write_file(&path, buf).map_err(|e| {
-        AppError::Custom(format!(
-            "Failed to write save file {}. Data might be corrupt now. Error: {:?}",
-            name, e
-        ))
-    })?;
-    Ok(())
+/// Returns the width of a trainer's column.
+/// On wide screens, the columns grow so longer names aren't cut off.
+fn column_width() -> u16 {
+    let (cols, _) = console::size();
+    // Outer border (2), list border (2) and divider (1).
+    (cols.saturating_sub(5) / 2).clamp(16, 28)
 }
@@ -0,0 +1,314 @@
+use crate::prelude::*;
+use core::mem;
+use pkmn_savedata::gba::GbaSave;
+
+/// How long the Pokemon take to cross the screen.
+const CROSSING_TIME: u32 = 2000;
+
+/// The frames of the evolution flourish as (duration in ms, shows the evolution, color).
+/// Frames without a color are drawn in the text color from the settings.
+const FLOURISH: [(u32, bool, Option<u8>); 9] = [
+    (300, false, None),
+    (100, true, Some(COLOR_LIGHTCYAN)),
+    (200, false, None),
+    (100, true, Some(COLOR_LIGHTCYAN)),
+    (100, false, None),
+    (150, true, Some(COLOR_LIGHTCYAN)),
+    (50, false, None),
+    (300, true, Some(COLOR_WHITE)),
+    (300, false, None),
+];
+
+/// The number of steps of the trade, see `Step`.
+const STEP_COUNT: usize = 6;
+
+/// The result of a `TradingScreen` after both saves were written.
+pub struct Traded {
+    /// Whether the user left the trade screen instead of trading again.
+    pub leave: bool,
+}
+
+/// The parts of a trade. One of them is done per frame, so the animation keeps playing
+/// while the saves are traded.
+enum Step {
+    Load,
+    Trade(LoadedSave, LoadedSave),
+    Serialize(GbaSave, GbaSave),
+    Verify(Vec<u8>, Vec<u8>),
+    WriteFirst(Vec<u8>, Vec<u8>),
+    WriteSecond(Vec<u8>),
+    Done,
+}
+
+/// Trades two party Pokemon and shows them swapping places in the meantime.
+pub struct TradingScreen {
+    saves: (String, String),
+    names: (String, String),
+    /// The indices of the traded Pokemon in their parties.
+    indices: (usize, usize),
+    /// The traded Pokemon, as the trade screen showed them. The new saves are checked
+    /// against them.
+    pokemon: (StoredPokemon, StoredPokemon),
+    step: Step,
+    steps_done: usize,
+    /// Time since the animation started.
+    elapsed: u32,
+}
+
+impl TradingScreen {
+    pub fn new(
+        saves: (String, String),
+        names: (String, String),
+        indices: (usize, usize),
+        pokemon: (StoredPokemon, StoredPokemon),
+    ) -> Self {
+        TradingScreen {
+            saves,
+            names,
+            indices,
+            pokemon,
+            step: Step::Load,
+            steps_done: 0,
+            elapsed: 0,
+        }
+    }
+
+    /// Does the next step of the trade.
+    fn work(&mut self) -> Result<()> {
+        let (p1, p2) = self.indices;
+        let saves = &self.saves;
+        self.step = match mem::replace(&mut self.step, Step::Done) {
+            Step::Load => Step::Trade(load_save(&saves.0)?, load_save(&saves.1)?),
+            Step::Trade(save1, save2) => {
+                let (save1, save2) = trade_pokemon(save1, save2, p1, p2)?;
+                Step::Serialize(save1, save2)
+            }
+            Step::Serialize(save1, save2) => {
+                let mut buf1 = save1
+                    .to_bytes()
+                    .map_err(|_| AppError::SerializeFailed(saves.0.clone()))?;
+                let mut buf2 = save2
+                    .to_bytes()
+                    .map_err(|_| AppError::SerializeFailed(saves.1.clone()))?;
+                move_mail(&mut buf1, &mut buf2, (&saves.0, &saves.1))?;
+                Step::Verify(buf1, buf2)
+            }
+            Step::Verify(buf1, buf2) => {
+                // Each save receives the other one's Pokemon.
+                verify_trade(&saves.0, &buf1, p1, &self.pokemon.1)?;
+                verify_trade(&saves.1, &buf2, p2, &self.pokemon.0)?;
+                Step::WriteFirst(buf1, buf2)
+            }
+            Step::WriteFirst(buf1, buf2) => {
+                write_save(&saves.0, &buf1)?;
+                Step::WriteSecond(buf2)
+            }
+            Step::WriteSecond(buf2) => {
+                // The first save already has the trade, so it has to be restored as well.
+                write_save(&saves.1, &buf2).map_err(|e| match e {
+                    AppError::WriteFailed { save, source, .. } => AppError::WriteFailed {
+                        save,
+                        written: vec![saves.0.clone()],
+                        source,
+                    },
+                    e => AppError::TradeIncomplete {
+                        written: saves.0.clone(),
+                        failed: saves.1.clone(),
+                        source: Box::new(e),
+                    },
+                })?;
+                // Load the same saves next time. The trade itself succeeded, so a settings
+                // file that can't be written isn't reported as an error.
+                let _ = update_settings(|settings| settings.last_saves = Some(saves.clone()));
+                Step::Done
+            }
+            Step::Done => Step::Done,
+        };
+        self.steps_done += 1;
+        Ok(())
+    }
+
+    /// Returns whether either of the Pokemon evolves when it is traded in-game.
+    fn evolves(&self) -> bool {
+        trade_evolution(&self.pokemon.0).is_some() || trade_evolution(&self.pokemon.1).is_some()
+    }
+
+    /// Returns the duration of the whole animation, at the speed set in the settings.
+    fn duration(&self) -> u32 {
+        let speed = settings().animation_speed;
+        let mut duration = speed.duration(CROSSING_TIME);
+        if self.evolves() {
+            duration += FLOURISH
+                .iter()
+                .map(|&(ms, _, _)| speed.duration(ms))
+                .sum::<u32>();
+        }
+        duration
+    }
+
+    /// Returns the frame of the flourish that should currently be shown, if it has started.
+    fn flourish_frame(&self) -> Option<(u32, bool, Option<u8>)> {
+        let speed = settings().animation_speed;
+        let crossing = speed.duration(CROSSING_TIME);
+        if !self.evolves() || self.elapsed < crossing {
+            return None;
+        }
+        let mut remaining = self.elapsed - crossing;
+        for frame in FLOURISH {
+            if remaining < speed.duration(frame.0) {
+                return Some(frame);
+            }
+            remaining -= speed.duration(frame.0);
+        }
+        FLOURISH.last().copied()
+    }
+
+    fn finished(&self) -> bool {
+        matches!(self.step, Step::Done) && self.elapsed >= self.duration()
+    }
+}
+
+impl Screen for TradingScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let (cols, _) = console::size();
+        let speed = settings().animation_speed;
+        let flourish = self.flourish_frame();
+        console::set_color(
+            flourish
+                .and_then(|(_, _, color)| color)
+                .unwrap_or(settings().color),
+        );
+
+        // Outer border (2) and the padding of the lanes (2).
+        let travel = cols.saturating_sub(4 + SPRITE_WIDTH);
+        let crossing = speed.duration(CROSSING_TIME);
+        let t = if crossing == 0 {
+            1.0
+        } else {
+            (self.elapsed.min(crossing) as f32) / (crossing as f32)
+        };
+        // Cubic ease-in-out, the Pokemon speed up and slow down again.
+        let ease = if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        };
+        let offset = (travel as f32 * ease) as u16;
+
+        // Shows a Pokemon, or its evolution during the flashes of the flourish.
+        let shown = |pokemon: &StoredPokemon| {
+            let evolution = match flourish {
+                Some((_, true, _)) => trade_evolution(pokemon),
+                _ => None,
+            };
+            evolution
+                .and_then(sprite)
+                .or_else(|| pokemon_sprite(pokemon))
+                .unwrap_or_default()
+        };
+        let sprite1 = shown(&self.pokemon.0);
+        let sprite2 = shown(&self.pokemon.1);
+
+        // The link cable between the games, with pulses that run along it.
+        let cable: String = (0..cols.saturating_sub(4))
+            .map(|i| {
+                if (i as u32 + self.elapsed / 80) % 6 == 0 {
+                    'o'
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+
+        let mut lines: Vec<String> = Vec::new();
+        if self.finished() {
+            lines.push(String::from(tr(Text::TradeSuccessful)));
+            for pokemon in [&self.pokemon.0, &self.pokemon.1] {
+                if trade_evolution(pokemon).is_some() {
+                    lines.push(trf(Text::EvolvesInGame, &[&pokemon.label()]));
+                }
+            }
+        } else {
+            lines.push(String::from(tr(Text::TradeInProgress)));
+            lines.push(String::from(tr(Text::SkipAnimation)));
+        }
+        let progress = self.steps_done as f32 / STEP_COUNT as f32;
+
+        let ui = border(column(widget_vec![
+            sizedbox(
+                align(text(tr(Text::Trading)))
+                    .horizontal(AlignHorizontal::Center)
+                    .vertical(AlignVertical::Center),
+            )
+            .height(3),
+            padding(row(widget_vec![
+                text(&self.names.0),
+                flexible(1, text("")),
+                text(&self.names.1),
+            ]))
+            .horizontal(1),
+            padding(column(widget_vec![
+                sizedbox(padding(image(sprite1)).left(offset)).height(SPRITE_HEIGHT),
+                text(cable),
+                sizedbox(padding(image(sprite2)).left(travel - offset)).height(SPRITE_HEIGHT),
+            ]))
+            .horizontal(1),
+            flexible(
+                1,
+                align(column(
+                    lines
+                        .iter()
+                        .map(|line| align(text(line)).horizontal(AlignHorizontal::Center))
+                        .collect()
+                ))
+                .vertical(AlignVertical::Center),
+            ),
+            padding(border(progress_bar(progress, '/', '|', ' ')).corners(CORNERS_ROUND))
+                .horizontal(2)
+                .bottom(1),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn tick(&mut self, dt: u32) -> Result<NavAction> {
+        self.elapsed = self.elapsed.saturating_add(dt).min(self.duration());
+        if matches!(self.step, Step::Done) {
+            return Ok(NavAction::None);
+        }
+        if let Err(e) = self.work() {
+            // The error screen takes this screen's place, so dismissing it returns
+            // to the trade screen.
+            console::set_color(settings().color);
+            return Ok(NavAction::Go(Box::new(ErrorScreen::new(e))));
+        }
+        Ok(NavAction::None)
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if !self.finished() {
+            // Any key skips the animation. The trade itself still has to finish.
+            self.elapsed = self.duration();
+            return Ok(NavAction::None);
+        }
+        Ok(NavAction::pop_with(Traded {
+            leave: matches!(input, InputKey::Escape),
+        }))
+    }
+}
@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+/// The width of the labels on the card.
+const LABEL_WIDTH: usize = 12;
+
+/// Shows the trainer card of a save, so the user can tell the loaded saves apart.
+pub struct TrainerCardScreen {
+    save: String,
+    card: Option<TrainerCard>,
+    focus: FocusManager,
+}
+
+impl TrainerCardScreen {
+    pub fn new<S: Into<String>>(save: S) -> Self {
+        TrainerCardScreen {
+            save: save.into(),
+            card: None,
+            focus: FocusManager::new(0),
+        }
+    }
+}
+
+impl Screen for TrainerCardScreen {
+    fn init(&mut self) -> Result<NavAction> {
+        self.card = Some(read_trainer_card(&self.save)?);
+        Ok(NavAction::None)
+    }
+
+    fn build(&mut self) {
+        let Some(card) = &self.card else {
+            return;
+        };
+
+        let (hours, minutes, _) = card.play_time;
+        let badges: String = card
+            .badges
+            .iter()
+            .map(|&badge| if badge { '*' } else { '.' })
+            .collect();
+        let rows = [
+            (tr(Text::CardGame), String::from(card.version.label())),
+            (tr(Text::CardName), card.name.clone()),
+            (
+                tr(Text::CardGender),
+                String::from(tr(if card.female { Text::Girl } else { Text::Boy })),
+            ),
+            (tr(Text::CardId), format!("{:05}", card.public_id)),
+            (tr(Text::CardSecretId), format!("{:05}", card.secret_id)),
+            (tr(Text::CardPlayTime), format!("{}:{:02}", hours, minutes)),
+            (tr(Text::CardMoney), format!("${}", card.money)),
+            (
+                tr(Text::CardBadges),
+                format!(
+                    "{} ({}/8)",
+                    badges,
+                    card.badges.iter().filter(|&&b| b).count()
+                ),
+            ),
+            (
+                "Pokedex",
+                trf(
+                    Text::CardDex,
+                    &[&card.dex_seen.to_string(), &card.dex_caught.to_string()],
+                ),
+            ),
+            (
+                tr(Text::CardHallOfFame),
+                String::from(tr(if card.hall_of_fame {
+                    Text::Yes
+                } else {
+                    Text::No
+                })),
+            ),
+        ];
+
+        let ui = border(column(widget_vec![
+            sizedbox(align(text(tr(Text::TrainerCard)))).height(3),
+            align(
+                border(column(
+                    rows.iter()
+                        .map(|(label, value)| {
+                            text(format!(" {:lw$} {:24}", label, value, lw = LABEL_WIDTH))
+                        })
+                        .collect()
+                ))
+                .corners(CORNERS_ROUND)
+            ),
+            text(""),
+            align(text(&self.save).max_width(40)),
+            text(""),
+            align(sizedbox(button(tr(Text::Back)).focus(&self.focus, 0)).width(12)),
+            flexible(1, text("")),
+        ]))
+        .borders(Borders {
+            left: Some('|'),
+            right: Some('|'),
+            top: Some('-'),
+            bottom: Some('-'),
+        })
+        .corners(Corners {
+            top_left: Some('+'),
+            top_right: Some('+'),
+            bottom_left: Some('+'),
+            bottom_right: Some('+'),
+        });
+
+        render_ui(ui);
+    }
+
+    fn handle_input(&mut self, input: InputKey) -> Result<NavAction> {
+        if let InputKey::Escape = input {
+            return Ok(NavAction::Pop);
+        }
+        match self.focus.handle_input(&input).activated() {
+            Some(_) => Ok(NavAction::Pop),
+            None => Ok(NavAction::None),
+        }
+    }
+}
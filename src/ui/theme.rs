@@ -1,5 +1,6 @@
 use crate::bindings::nspireio::{
-    NIO_COLOR_LIGHTMAGENTA, NIO_COLOR_LIGHTYELLOW, NIO_COLOR_MAGENTA, NIO_COLOR_YELLOW,
+    NIO_COLOR_LIGHTCYAN, NIO_COLOR_LIGHTGREEN, NIO_COLOR_LIGHTMAGENTA, NIO_COLOR_LIGHTYELLOW,
+    NIO_COLOR_MAGENTA, NIO_COLOR_YELLOW,
 };
 
 use super::widgets::Corners;
@@ -38,3 +39,14 @@ pub const COLOR_MAGENTA: u8 = NIO_COLOR_MAGENTA as u8;
 pub const COLOR_LIGHTMAGENTA: u8 = NIO_COLOR_LIGHTMAGENTA as u8;
 pub const COLOR_YELLOW: u8 = NIO_COLOR_YELLOW as u8;
 pub const COLOR_LIGHTYELLOW: u8 = NIO_COLOR_LIGHTYELLOW as u8;
+pub const COLOR_LIGHTGREEN: u8 = NIO_COLOR_LIGHTGREEN as u8;
+pub const COLOR_LIGHTCYAN: u8 = NIO_COLOR_LIGHTCYAN as u8;
+
+/// The text colors that can be picked in the settings, with their names.
+pub const TEXT_COLORS: [(&str, u8); 5] = [
+    ("White", COLOR_WHITE),
+    ("Green", COLOR_LIGHTGREEN),
+    ("Cyan", COLOR_LIGHTCYAN),
+    ("Yellow", COLOR_LIGHTYELLOW),
+    ("Magenta", COLOR_LIGHTMAGENTA),
+];
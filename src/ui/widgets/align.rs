@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, Widget};
 
 /// Options for horizontal alignment.
 #[derive(PartialEq)]
@@ -60,8 +60,9 @@ impl Widget for Align {
     fn min_size(&self) -> (u16, u16) {
         self.child.min_size()
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let width = canvas.width();
+        let height = canvas.height();
         let (child_w, child_h) = self.child.min_size();
         let render_w = match self.alignment.horizontal {
             AlignHorizontal::Stretch => width,
@@ -81,9 +82,8 @@ impl Widget for Align {
             AlignVertical::Center => (height - render_h) / 2,
             AlignVertical::End => height - render_h,
         };
-        let child_canvas = self.child.render(render_w, render_h);
-        overlay(&mut canvas, &child_canvas, offset_x, offset_y);
-        canvas
+        self.child
+            .render(&mut canvas.sub(offset_x, offset_y, render_w, render_h));
     }
 }
 
@@ -1,6 +1,6 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 
 /// Represents the characters used for each side of a border.
 /// If an Option is None, that side is not drawn.
@@ -34,6 +34,7 @@ pub struct Border {
     pub child: Box<dyn Widget>,
     pub borders: Borders,
     pub corners: Corners,
+    size_cache: SizeCache,
 }
 
 impl Border {
@@ -52,6 +53,7 @@ impl Border {
                 bottom_left: Some('+'),
                 bottom_right: Some('+'),
             },
+            size_cache: SizeCache::new(),
         }
     }
 
@@ -68,71 +70,103 @@ impl Border {
     }
 }
 
+impl Border {
+    /// Returns the corner character if both adjacent borders exist (`None` otherwise).
+    /// The inner `None` means the corner is left transparent.
+    fn corner(
+        &self,
+        vertical: Option<char>,
+        horizontal: Option<char>,
+        corner: Option<char>,
+    ) -> Option<Option<char>> {
+        if vertical.is_some() && horizontal.is_some() {
+            Some(corner)
+        } else {
+            None
+        }
+    }
+}
+
 impl Widget for Border {
     fn min_size(&self) -> (u16, u16) {
-        let (child_w, child_h) = self.child.min_size();
-        let extra_w = if self.borders.left.is_some() { 1 } else { 0 }
-            + if self.borders.right.is_some() { 1 } else { 0 };
-        let extra_h = if self.borders.top.is_some() { 1 } else { 0 }
-            + if self.borders.bottom.is_some() { 1 } else { 0 };
-        (child_w + extra_w, child_h + extra_h)
+        self.size_cache.get_or_measure(|| {
+            let (child_w, child_h) = self.child.min_size();
+            let extra_w = if self.borders.left.is_some() { 1 } else { 0 }
+                + if self.borders.right.is_some() { 1 } else { 0 };
+            let extra_h = if self.borders.top.is_some() { 1 } else { 0 }
+                + if self.borders.bottom.is_some() { 1 } else { 0 };
+            (child_w + extra_w, child_h + extra_h)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        // Ensure the canvas is at least as big as min_size.
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        // Ensure the view is at least as big as min_size, anything beyond the
+        // allocated area is clipped.
         let (min_w, min_h) = self.min_size();
-        let width = if width < min_w { min_w } else { width };
-        let height = if height < min_h { min_h } else { height };
+        let width = canvas.width().max(min_w);
+        let height = canvas.height().max(min_h);
+        let mut canvas = canvas.sub(0, 0, width, height);
 
-        let mut canvas = create_canvas(width, height);
+        // Corners are only drawn where both adjacent borders exist. A missing corner
+        // character leaves the cell transparent, so the border lines must skip it.
+        let top_left = self.corner(self.borders.top, self.borders.left, self.corners.top_left);
+        let top_right = self.corner(self.borders.top, self.borders.right, self.corners.top_right);
+        let bottom_left = self.corner(
+            self.borders.bottom,
+            self.borders.left,
+            self.corners.bottom_left,
+        );
+        let bottom_right = self.corner(
+            self.borders.bottom,
+            self.borders.right,
+            self.corners.bottom_right,
+        );
+        let is_corner = |x: u16, y: u16| {
+            let left = x == 0;
+            let right = x == width - 1;
+            let top = y == 0;
+            let bottom = y == height - 1;
+            (top && left && top_left.is_some())
+                || (top && right && top_right.is_some())
+                || (bottom && left && bottom_left.is_some())
+                || (bottom && right && bottom_right.is_some())
+        };
 
         // Draw horizontal borders.
         if let Some(top_char) = self.borders.top {
-            for x in 0..width as usize {
-                canvas[0][x] = top_char;
+            for x in (0..width).filter(|&x| !is_corner(x, 0)) {
+                canvas.put(x, 0, top_char);
             }
         }
         if let Some(bottom_char) = self.borders.bottom {
-            for x in 0..width as usize {
-                canvas[(height - 1) as usize][x] = bottom_char;
+            for x in (0..width).filter(|&x| !is_corner(x, height - 1)) {
+                canvas.put(x, height - 1, bottom_char);
             }
         }
 
         // Draw vertical borders.
         if let Some(left_char) = self.borders.left {
-            for y in 0..height as usize {
-                canvas[y][0] = left_char;
+            for y in (0..height).filter(|&y| !is_corner(0, y)) {
+                canvas.put(0, y, left_char);
             }
         }
         if let Some(right_char) = self.borders.right {
-            for y in 0..height as usize {
-                canvas[y][(width - 1) as usize] = right_char;
+            for y in (0..height).filter(|&y| !is_corner(width - 1, y)) {
+                canvas.put(width - 1, y, right_char);
             }
         }
 
-        // Draw corners if corresponding borders exist.
-        if self.borders.top.is_some() && self.borders.left.is_some() {
-            canvas[0][0] = match self.corners.top_left {
-                Some(c) => c,
-                None => ' ',
-            };
+        // Draw corners.
+        if let Some(Some(c)) = top_left {
+            canvas.put(0, 0, c);
         }
-        if self.borders.top.is_some() && self.borders.right.is_some() {
-            canvas[0][(width - 1) as usize] = match self.corners.top_right {
-                Some(c) => c,
-                None => ' ',
-            };
+        if let Some(Some(c)) = top_right {
+            canvas.put(width - 1, 0, c);
         }
-        if self.borders.bottom.is_some() && self.borders.left.is_some() {
-            canvas[(height - 1) as usize][0] = match self.corners.bottom_left {
-                Some(c) => c,
-                None => ' ',
-            };
+        if let Some(Some(c)) = bottom_left {
+            canvas.put(0, height - 1, c);
         }
-        if self.borders.bottom.is_some() && self.borders.right.is_some() {
-            canvas[(height - 1) as usize][(width - 1) as usize] = match self.corners.bottom_right {
-                Some(c) => c,
-                None => ' ',
-            };
+        if let Some(Some(c)) = bottom_right {
+            canvas.put(width - 1, height - 1, c);
         }
 
         // Compute inner region for the child.
@@ -141,10 +175,8 @@ impl Widget for Border {
         let inner_width = width - x_offset - if self.borders.right.is_some() { 1 } else { 0 };
         let inner_height = height - y_offset - if self.borders.bottom.is_some() { 1 } else { 0 };
 
-        let child_canvas = self.child.render(inner_width, inner_height);
-        overlay(&mut canvas, &child_canvas, x_offset, y_offset);
-
-        canvas
+        self.child
+            .render(&mut canvas.sub(x_offset, y_offset, inner_width, inner_height));
     }
 }
 
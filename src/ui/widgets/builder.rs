@@ -1,12 +1,15 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
 use crate::ui::rendering::{Canvas, IntoWidget, Widget};
+use core::cell::OnceCell;
 
 pub struct BuilderWidget<F>
 where
     F: Fn() -> Box<dyn Widget>,
 {
     pub builder: F,
+    /// The widget produced by the closure, built once on first use.
+    built: OnceCell<Box<dyn Widget>>,
 }
 
 impl<F> BuilderWidget<F>
@@ -14,7 +17,15 @@ where
     F: Fn() -> Box<dyn Widget>,
 {
     pub fn new(builder: F) -> Self {
-        Self { builder }
+        Self {
+            builder,
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Returns the built child, invoking the closure on first use.
+    fn child(&self) -> &dyn Widget {
+        self.built.get_or_init(|| (self.builder)()).as_ref()
     }
 }
 
@@ -23,10 +34,10 @@ where
     F: Fn() -> Box<dyn Widget>,
 {
     fn min_size(&self) -> (u16, u16) {
-        (self.builder)().min_size()
+        self.child().min_size()
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        (self.builder)().render(width, height)
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        self.child().render(canvas)
     }
 }
 
@@ -44,6 +55,7 @@ where
 ///     text("Dynamic content")
 /// });
 /// ```
+#[allow(dead_code)]
 pub fn builder<F, W>(builder: F) -> BuilderWidget<impl Fn() -> Box<dyn Widget>>
 where
     F: Fn() -> W,
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use crate::ui::rendering::{Canvas, IntoWidget, Widget};
+use core::cell::OnceCell;
 
 pub struct Button {
     pub text: String,
@@ -8,6 +9,10 @@ pub struct Button {
     pub corners: Option<Corners>,
     pub borders_selected: Option<Borders>,
     pub corners_selected: Option<Corners>,
+    /// The tap target id of the button, if it can be focused.
+    pub id: Option<usize>,
+    /// The inner widget tree, built once on first use.
+    inner: OnceCell<Box<dyn Widget>>,
 }
 
 const BORDERS_BTN_SELECTED: Borders = Borders {
@@ -38,15 +43,30 @@ impl Button {
             corners: None,
             borders_selected: None,
             corners_selected: None,
+            id: None,
+            inner: OnceCell::new(),
         }
     }
 
     /// Sets the selection state of the button.
     pub fn selected(mut self, sel: bool) -> Self {
         self.is_selected = sel;
+        self.inner = OnceCell::new();
         self
     }
 
+    /// Makes the button focusable with the given id. It is selected while it has the focus.
+    pub fn focus(self, focus: &FocusManager, id: usize) -> Self {
+        let mut button = self.selected(focus.is_focused(id));
+        button.id = Some(id);
+        button
+    }
+
+    /// Returns the inner widget, building it on first use.
+    fn inner(&self) -> &dyn Widget {
+        self.inner.get_or_init(|| self.build_widget()).as_ref()
+    }
+
     /// Helper method that builds the inner widget (Text wrapped in Border) with the
     /// appropriate border and corner style, depending on selection state.
     fn build_widget(&self) -> Box<dyn Widget> {
@@ -82,12 +102,15 @@ impl Button {
 impl Widget for Button {
     fn min_size(&self) -> (u16, u16) {
         // Delegate size calculation to the constructed widget.
-        self.build_widget().min_size()
+        self.inner().min_size()
     }
 
-    fn render(&self, width: u16, height: u16) -> Canvas {
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        if let Some(id) = self.id {
+            canvas.mark(id);
+        }
         // Delegate rendering to the constructed widget.
-        self.build_widget().render(width, height)
+        self.inner().render(canvas)
     }
 }
 
@@ -99,6 +122,7 @@ impl Widget for Button {
 /// use widgets::button::button;
 ///
 /// let btn = button("Click Me").selected(true);
+/// let focusable = button("Click Me").focus(&focus, 0);
 /// ```
 pub fn button<S: Into<String>>(s: S) -> Button {
     Button::new(s)
@@ -1,34 +1,39 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 
 pub struct Column {
     pub children: Vec<Box<dyn Widget>>,
+    size_cache: SizeCache,
 }
 
 impl Column {
     pub fn new<T: IntoWidget>(children: Vec<T>) -> Self {
         Self {
             children: children.into_iter().map(|c| c.into_widget()).collect(),
+            size_cache: SizeCache::new(),
         }
     }
 }
 
 impl Widget for Column {
     fn min_size(&self) -> (u16, u16) {
-        let mut total_height = 0;
-        let mut max_width = 0;
-        for child in &self.children {
-            let (w, h) = child.min_size();
-            total_height += h;
-            if w > max_width {
-                max_width = w;
+        self.size_cache.get_or_measure(|| {
+            let mut total_height = 0;
+            let mut max_width = 0;
+            for child in &self.children {
+                let (w, h) = child.min_size();
+                total_height += h;
+                if w > max_width {
+                    max_width = w;
+                }
             }
-        }
-        (max_width, total_height)
+            (max_width, total_height)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let width = canvas.width();
+        let height = canvas.height();
         let n = self.children.len();
         let mut allocated_heights = vec![0u16; n];
         let mut total_min = 0;
@@ -56,14 +61,12 @@ impl Widget for Column {
         }
         let mut y_offset = 0;
         for (i, child) in self.children.iter().enumerate() {
-            let child_canvas = child.render(width, allocated_heights[i]);
-            overlay(&mut canvas, &child_canvas, 0, y_offset);
+            child.render(&mut canvas.sub(0, y_offset, width, allocated_heights[i]));
             y_offset += allocated_heights[i];
             if y_offset >= height {
                 break;
             }
         }
-        canvas
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::ui::rendering::{create_canvas, Canvas, Widget};
+use crate::ui::rendering::{Canvas, Widget};
 
 /// Orientation for the divider.
 #[derive(Clone, Copy)]
@@ -29,22 +29,20 @@ impl Widget for Divider {
     fn min_size(&self) -> (u16, u16) {
         (1, 1)
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let width = canvas.width();
+        let height = canvas.height();
         match self.orientation {
             Orientation::Horizontal => {
-                let mut canvas = create_canvas(width, 1);
-                for x in 0..width as usize {
-                    canvas[0][x] = self.ch;
+                for x in 0..width {
+                    canvas.put(x, 0, self.ch);
                 }
-                canvas
             }
             Orientation::Vertical => {
-                let mut canvas = create_canvas(width, height);
                 let col = if width > 1 { width / 2 } else { 0 };
-                for r in 0..height as usize {
-                    canvas[r][col as usize] = self.ch;
+                for r in 0..height {
+                    canvas.put(col, r, self.ch);
                 }
-                canvas
             }
         }
     }
@@ -21,8 +21,8 @@ impl Widget for Flexible {
     fn min_size(&self) -> (u16, u16) {
         self.child.min_size()
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        self.child.render(width, height)
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        self.child.render(canvas)
     }
     fn flex_factor(&self) -> Option<i32> {
         Some(self.flex)
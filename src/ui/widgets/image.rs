@@ -1,48 +1,49 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, Canvas, Widget};
+use crate::ui::rendering::{Canvas, SizeCache, Widget};
+#[cfg(feature = "calculator-build")]
+use ndless::alloc::borrow::Cow;
+#[cfg(not(feature = "calculator-build"))]
+use std::borrow::Cow;
 
-pub struct Image<'a> {
-    pub data: &'a str,
+pub struct Image {
+    /// The ASCII art. Constant images are borrowed, generated ones like sprites are owned.
+    pub data: Cow<'static, str>,
+    size_cache: SizeCache,
 }
 
-impl<'a> Image<'a> {
+impl Image {
     /// Creates a new Image widget from the given ASCII art data.
-    pub fn new(data: &'a str) -> Self {
-        Self { data }
+    pub fn new<S: Into<Cow<'static, str>>>(data: S) -> Self {
+        Self {
+            data: data.into(),
+            size_cache: SizeCache::new(),
+        }
     }
 }
 
-impl<'a> Widget for Image<'a> {
+impl Widget for Image {
     fn min_size(&self) -> (u16, u16) {
-        // Split the string into lines.
-        let lines: Vec<&str> = self.data.lines().collect();
-        let height = lines.len() as u16;
-        // Determine the maximum length (in characters) among all lines.
-        let width = lines
-            .iter()
-            .map(|line| line.chars().count())
-            .max()
-            .unwrap_or(0) as u16;
-        (width, height)
+        self.size_cache.get_or_measure(|| {
+            // Split the string into lines.
+            let lines: Vec<&str> = self.data.lines().collect();
+            let height = lines.len() as u16;
+            // Determine the maximum length (in characters) among all lines.
+            let width = lines
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0) as u16;
+            (width, height)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        // Create a canvas with the given allocated dimensions.
-        let mut canvas = create_canvas(width, height);
-        // Split the data into lines.
-        let lines: Vec<&str> = self.data.lines().collect();
-        for (i, line) in lines.iter().enumerate() {
-            if i as u16 >= height {
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        for (i, line) in self.data.lines().enumerate() {
+            if i as u16 >= canvas.height() {
                 break;
             }
-            for (j, ch) in line.chars().enumerate() {
-                if j as u16 >= width {
-                    break;
-                }
-                canvas[i][j] = ch;
-            }
+            canvas.put_str(0, i as u16, line);
         }
-        canvas
     }
 }
 
@@ -61,6 +62,6 @@ impl<'a> Widget for Image<'a> {
 ///
 /// let img = image(PIKACHU);
 /// ```
-pub fn image(data: &str) -> Image {
+pub fn image<S: Into<Cow<'static, str>>>(data: S) -> Image {
     Image::new(data)
 }
@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use crate::ui::rendering::{Canvas, Widget};
+
+/// An on-screen keyboard, for typing with the arrow keys.
+pub struct Keyboard<'a> {
+    pub rows: &'a [&'a str],
+    /// The highlighted key as (column, row), if any.
+    pub selected: Option<(usize, usize)>,
+    /// The tap target id of the first key, if the keys can be focused.
+    /// The keys are numbered row by row from there.
+    pub first_id: Option<usize>,
+}
+
+impl<'a> Keyboard<'a> {
+    pub fn new(rows: &'a [&'a str]) -> Self {
+        Self {
+            rows,
+            selected: None,
+            first_id: None,
+        }
+    }
+
+    /// Highlights the key at (column, row).
+    pub fn selected(mut self, selected: Option<(usize, usize)>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Makes every key focusable, numbering them row by row starting at `first_id`.
+    /// The focused key is highlighted.
+    pub fn focus(mut self, focus: &FocusManager, first_id: usize) -> Self {
+        self.first_id = Some(first_id);
+        self.selected = focus
+            .focused()
+            .and_then(|id| id.checked_sub(first_id))
+            .and_then(|index| self.position(index));
+        self
+    }
+
+    /// Returns the (column, row) of the key with the given row-by-row index.
+    fn position(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (y, row) in self.rows.iter().enumerate() {
+            let len = row.chars().count();
+            if index < len {
+                return Some((index, y));
+            }
+            index -= len;
+        }
+        None
+    }
+}
+
+impl<'a> Widget for Keyboard<'a> {
+    fn min_size(&self) -> (u16, u16) {
+        // Every key takes 3 cells: the character and room for the highlight brackets.
+        let columns = self
+            .rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        (columns as u16 * 3, self.rows.len() as u16)
+    }
+
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let mut id = self.first_id;
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                let cell = x as u16 * 3;
+                if let Some(key_id) = id {
+                    canvas.sub(cell, y as u16, 3, 1).mark(key_id);
+                    id = Some(key_id + 1);
+                }
+                if self.selected == Some((x, y)) {
+                    canvas.put(cell, y as u16, '[');
+                    canvas.put(cell + 2, y as u16, ']');
+                }
+                // Show the space key, otherwise it would be invisible.
+                canvas.put(cell + 1, y as u16, if ch == ' ' { '_' } else { ch });
+            }
+        }
+    }
+}
+
+/// Creates a Keyboard widget with the given rows of keys.
+///
+/// # Example
+///
+/// ```rust
+/// use widgets::keyboard::keyboard;
+///
+/// let kb = keyboard(&["ABC", "DEF"]).selected(Some((1, 0)));
+/// let focusable = keyboard(&["ABC", "DEF"]).focus(&focus, 10);
+/// ```
+pub fn keyboard<'a>(rows: &'a [&'a str]) -> Keyboard<'a> {
+    Keyboard::new(rows)
+}
@@ -6,12 +6,15 @@ pub mod column;
 pub mod divider;
 pub mod flexible;
 pub mod image;
+pub mod keyboard;
 pub mod padding;
 pub mod progress_bar;
 pub mod row;
 pub mod sizedbox;
 pub mod stack;
+pub mod tap_target;
 pub mod text;
+pub mod text_field;
 
 pub use align::{align, AlignHorizontal, AlignVertical};
 pub use border::{border, Borders, Corners};
@@ -21,9 +24,12 @@ pub use column::column;
 pub use divider::divider;
 pub use flexible::flexible;
 pub use image::image;
+pub use keyboard::keyboard;
 pub use padding::padding;
 pub use progress_bar::progress_bar;
 pub use row::row;
 pub use sizedbox::sizedbox;
 pub use stack::stack;
+pub use tap_target::tap_target;
 pub use text::text;
+pub use text_field::{text_field, TextInput};
@@ -1,6 +1,6 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 
 pub struct Padding {
     pub child: Box<dyn Widget>,
@@ -8,6 +8,7 @@ pub struct Padding {
     pub left: u16,
     pub right: u16,
     pub bottom: u16,
+    size_cache: SizeCache,
 }
 
 impl Padding {
@@ -18,6 +19,7 @@ impl Padding {
             left: 0,
             right: 0,
             bottom: 0,
+            size_cache: SizeCache::new(),
         }
     }
     /// Sets the top padding.
@@ -66,23 +68,21 @@ impl Padding {
 
 impl Widget for Padding {
     fn min_size(&self) -> (u16, u16) {
-        let (child_w, child_h) = self.child.min_size();
-        (
-            child_w + self.left + self.right,
-            child_h + self.top + self.bottom,
-        )
+        self.size_cache.get_or_measure(|| {
+            let (child_w, child_h) = self.child.min_size();
+            (
+                child_w + self.left + self.right,
+                child_h + self.top + self.bottom,
+            )
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        // Create a canvas with the allocated width and height.
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
         // Determine inner region size for the child.
-        let inner_width = width.saturating_sub(self.left + self.right);
-        let inner_height = height.saturating_sub(self.top + self.bottom);
-        // Render the child into the inner area.
-        let child_canvas = self.child.render(inner_width, inner_height);
-        // Overlay the child's canvas at the position defined by the padding.
-        overlay(&mut canvas, &child_canvas, self.left, self.top);
-        canvas
+        let inner_width = canvas.width().saturating_sub(self.left + self.right);
+        let inner_height = canvas.height().saturating_sub(self.top + self.bottom);
+        // Render the child into the inner area at the position defined by the padding.
+        self.child
+            .render(&mut canvas.sub(self.left, self.top, inner_width, inner_height));
     }
 }
 
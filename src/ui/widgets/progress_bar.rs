@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, Canvas, Widget};
+use crate::ui::rendering::{Canvas, Widget};
 
 pub struct ProgressBar {
     /// A value between 0.0 and 1.0 indicating progress.
@@ -25,28 +25,25 @@ impl Widget for ProgressBar {
         // Minimum size is 5 columns by 1 row.
         (5, 1)
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        let mut canvas = create_canvas(width, height);
-        // Fill the entire area with the background character.
-        for row in canvas.iter_mut() {
-            for ch in row.iter_mut() {
-                *ch = self.background;
-            }
-        }
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let width = canvas.width();
+        let height = canvas.height();
         // Clamp the fraction between 0 and 1.
         let fraction = self.fraction.max(0.0).min(1.0);
         let active_cells = ((width as f32) * fraction).floor() as usize;
-        // For every row, fill the active area.
-        for r in 0..height as usize {
-            for x in 0..(width as usize).min(active_cells) {
-                if x == active_cells.saturating_sub(1) && active_cells > 0 && fraction < 1.0 {
-                    canvas[r][x] = self.tip;
+        // For every row, fill the active area and the background behind it.
+        for r in 0..height {
+            for x in 0..width {
+                let ch = if (x as usize) >= active_cells {
+                    self.background
+                } else if x as usize == active_cells - 1 && fraction < 1.0 {
+                    self.tip
                 } else {
-                    canvas[r][x] = self.foreground;
-                }
+                    self.foreground
+                };
+                canvas.put(x, r, ch);
             }
         }
-        canvas
     }
 }
 
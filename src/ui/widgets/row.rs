@@ -1,34 +1,38 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 pub struct Row {
     pub children: Vec<Box<dyn Widget>>,
+    size_cache: SizeCache,
 }
 
 impl Row {
     pub fn new<T: IntoWidget>(children: Vec<T>) -> Self {
         Self {
             children: children.into_iter().map(|c| c.into_widget()).collect(),
+            size_cache: SizeCache::new(),
         }
     }
 }
 
 impl Widget for Row {
     fn min_size(&self) -> (u16, u16) {
-        let mut total_width = 0;
-        let mut max_height = 0;
-        for child in &self.children {
-            let (w, h) = child.min_size();
-            total_width += w;
-            if h > max_height {
-                max_height = h;
+        self.size_cache.get_or_measure(|| {
+            let mut total_width = 0;
+            let mut max_height = 0;
+            for child in &self.children {
+                let (w, h) = child.min_size();
+                total_width += w;
+                if h > max_height {
+                    max_height = h;
+                }
             }
-        }
-        (total_width, max_height)
+            (total_width, max_height)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        // Create a canvas with the full allocated width and height.
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        let width = canvas.width();
+        let height = canvas.height();
         // Get each child's natural (minimum) width.
         let min_widths: Vec<u16> = self.children.iter().map(|c| c.min_size().0).collect();
         let total_min: u16 = min_widths.iter().sum();
@@ -83,14 +87,12 @@ impl Widget for Row {
         // Render each child into its allocated width.
         let mut x_offset = 0;
         for (i, child) in self.children.iter().enumerate() {
-            let child_canvas = child.render(allocated_widths[i], height);
-            overlay(&mut canvas, &child_canvas, x_offset, 0);
+            child.render(&mut canvas.sub(x_offset, 0, allocated_widths[i], height));
             x_offset += allocated_widths[i];
             if x_offset >= width {
                 break;
             }
         }
-        canvas
     }
 }
 
@@ -1,11 +1,12 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 
 pub struct SizedBox {
     pub child: Box<dyn Widget>,
     pub forced_width: Option<u16>,
     pub forced_height: Option<u16>,
+    size_cache: SizeCache,
 }
 
 impl SizedBox {
@@ -14,6 +15,7 @@ impl SizedBox {
             child: child.into_widget(),
             forced_width: None,
             forced_height: None,
+            size_cache: SizeCache::new(),
         }
     }
 
@@ -32,22 +34,20 @@ impl SizedBox {
 
 impl Widget for SizedBox {
     fn min_size(&self) -> (u16, u16) {
-        let (child_w, child_h) = self.child.min_size();
-        let w = self.forced_width.unwrap_or(child_w);
-        let h = self.forced_height.unwrap_or(child_h);
-        (w, h)
+        self.size_cache.get_or_measure(|| {
+            let (child_w, child_h) = self.child.min_size();
+            let w = self.forced_width.unwrap_or(child_w);
+            let h = self.forced_height.unwrap_or(child_h);
+            (w, h)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        // Create a canvas of the allocated size.
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
         // Determine the size to render the child: if forced, use that; otherwise, use the parent's allocation.
-        let child_width = self.forced_width.unwrap_or(width);
-        let child_height = self.forced_height.unwrap_or(height);
-        // Render the child using these dimensions.
-        let child_canvas = self.child.render(child_width, child_height);
-        // Overlay the child's canvas at the top-left corner.
-        overlay(&mut canvas, &child_canvas, 0, 0);
-        canvas
+        let child_width = self.forced_width.unwrap_or(canvas.width());
+        let child_height = self.forced_height.unwrap_or(canvas.height());
+        // Render the child at the top-left corner, clipped to the allocated area.
+        self.child
+            .render(&mut canvas.sub(0, 0, child_width, child_height));
     }
 }
 
@@ -1,41 +1,42 @@
 #[cfg(feature = "calculator-build")]
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, overlay, Canvas, IntoWidget, Widget};
+use crate::ui::rendering::{Canvas, IntoWidget, SizeCache, Widget};
 
 pub struct Stack {
     pub children: Vec<Box<dyn Widget>>,
+    size_cache: SizeCache,
 }
 
 impl Stack {
     pub fn new<T: IntoWidget>(children: Vec<T>) -> Self {
         Self {
             children: children.into_iter().map(|c| c.into_widget()).collect(),
+            size_cache: SizeCache::new(),
         }
     }
 }
 
 impl Widget for Stack {
     fn min_size(&self) -> (u16, u16) {
-        let mut max_w = 0;
-        let mut max_h = 0;
-        for child in &self.children {
-            let (w, h) = child.min_size();
-            if w > max_w {
-                max_w = w;
-            }
-            if h > max_h {
-                max_h = h;
+        self.size_cache.get_or_measure(|| {
+            let mut max_w = 0;
+            let mut max_h = 0;
+            for child in &self.children {
+                let (w, h) = child.min_size();
+                if w > max_w {
+                    max_w = w;
+                }
+                if h > max_h {
+                    max_h = h;
+                }
             }
-        }
-        (max_w, max_h)
+            (max_w, max_h)
+        })
     }
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
         for child in &self.children {
-            let child_canvas = child.render(width, height);
-            overlay(&mut canvas, &child_canvas, 0, 0);
+            child.render(canvas);
         }
-        canvas
     }
 }
 
@@ -0,0 +1,48 @@
+#[cfg(feature = "calculator-build")]
+use crate::prelude::*;
+use crate::ui::rendering::{Canvas, IntoWidget, Widget};
+
+/// Reports the area its child was laid out in, so taps and clicks on it can be found
+/// with `hit_test`. Doesn't change the layout or look of the child.
+pub struct TapTarget {
+    pub id: usize,
+    pub child: Box<dyn Widget>,
+}
+
+impl TapTarget {
+    pub fn new<T: IntoWidget>(id: usize, child: T) -> Self {
+        Self {
+            id,
+            child: child.into_widget(),
+        }
+    }
+}
+
+impl Widget for TapTarget {
+    fn min_size(&self) -> (u16, u16) {
+        self.child.min_size()
+    }
+
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        canvas.mark(self.id);
+        self.child.render(canvas);
+    }
+
+    fn flex_factor(&self) -> Option<i32> {
+        self.child.flex_factor()
+    }
+}
+
+/// Creates a TapTarget widget that marks its child as tappable with the given id.
+///
+/// # Example
+///
+/// ```rust
+/// use widgets::tap_target::tap_target;
+/// use widgets::button::button;
+///
+/// let ok = tap_target(0, button("OK"));
+/// ```
+pub fn tap_target<T: IntoWidget>(id: usize, child: T) -> TapTarget {
+    TapTarget::new(id, child)
+}
@@ -1,9 +1,10 @@
 use crate::prelude::*;
-use crate::ui::rendering::{create_canvas, Canvas, Widget};
+use crate::ui::rendering::{Canvas, SizeCache, Widget};
 
 pub struct Text {
     pub text: String,
     pub max_width: Option<u16>,
+    size_cache: SizeCache,
 }
 
 impl Text {
@@ -11,6 +12,7 @@ impl Text {
         Self {
             text: s.into(),
             max_width: None,
+            size_cache: SizeCache::new(),
         }
     }
 
@@ -49,22 +51,23 @@ impl Text {
 
 impl Widget for Text {
     fn min_size(&self) -> (u16, u16) {
-        let lines = self.wrapped_lines();
-        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
-        (width as u16, lines.len() as u16)
+        self.size_cache.get_or_measure(|| {
+            let lines = self.wrapped_lines();
+            let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+            (width as u16, lines.len() as u16)
+        })
     }
 
-    fn render(&self, width: u16, height: u16) -> Canvas {
-        let mut canvas = create_canvas(width, height);
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        if self.max_width.is_none() {
+            // Unwrapped text is drawn straight from the string without allocating.
+            canvas.put_str(0, 0, &self.text);
+            return;
+        }
         let lines = self.wrapped_lines();
-        for (y, line) in lines.iter().enumerate().take(height as usize) {
-            let chars: Vec<char> = line.chars().collect();
-            let len = chars.len().min(width as usize);
-            for x in 0..len {
-                canvas[y][x] = chars[x];
-            }
+        for (y, line) in lines.iter().enumerate().take(canvas.height() as usize) {
+            canvas.put_str(0, y as u16, line);
         }
-        canvas
     }
 }
 
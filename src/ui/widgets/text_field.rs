@@ -0,0 +1,149 @@
+use crate::prelude::*;
+use crate::ui::rendering::{Canvas, Widget};
+
+/// The editable state of a text field: the text, the cursor and the editing rules.
+///
+/// Screens own the state and pass it to the `text_field` widget when building the ui.
+#[derive(Clone)]
+pub struct TextInput {
+    pub chars: Vec<char>,
+    /// The cursor position, between 0 and `chars.len()`.
+    pub cursor: usize,
+    pub max_length: usize,
+    /// Characters that are rejected by this function are ignored when typed.
+    pub allowed: fn(char) -> bool,
+}
+
+impl TextInput {
+    pub fn new<S: Into<String>>(value: S, max_length: usize) -> Self {
+        let chars: Vec<char> = value.into().chars().take(max_length).collect();
+        Self {
+            cursor: chars.len(),
+            chars,
+            max_length,
+            allowed: |_| true,
+        }
+    }
+
+    /// Restricts the characters that can be typed.
+    pub fn allowed(mut self, allowed: fn(char) -> bool) -> Self {
+        self.allowed = allowed;
+        self
+    }
+
+    /// Returns the current text.
+    pub fn value(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Inserts a character at the cursor. Returns false if it was rejected.
+    pub fn insert(&mut self, ch: char) -> bool {
+        if self.chars.len() >= self.max_length || !(self.allowed)(ch) {
+            return false;
+        }
+        self.chars.insert(self.cursor, ch);
+        self.cursor += 1;
+        true
+    }
+
+    /// Deletes the character before the cursor.
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    /// Applies an editing key (characters, backspace, left and right).
+    /// Returns true if the key was handled.
+    pub fn handle_input(&mut self, input: &InputKey) -> bool {
+        match input {
+            InputKey::Char(ch) => {
+                self.insert(*ch);
+                true
+            }
+            InputKey::Backspace => {
+                self.backspace();
+                true
+            }
+            InputKey::Left => {
+                self.cursor = self.cursor.saturating_sub(1);
+                true
+            }
+            InputKey::Right => {
+                self.cursor = min(self.cursor + 1, self.chars.len());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub struct TextField {
+    pub chars: Vec<char>,
+    pub cursor: usize,
+    pub length: usize,
+    pub is_focused: bool,
+    /// The tap target id of the field, if it can be focused.
+    pub id: Option<usize>,
+}
+
+impl TextField {
+    pub fn new(input: &TextInput) -> Self {
+        Self {
+            chars: input.chars.clone(),
+            cursor: input.cursor,
+            length: input.max_length,
+            is_focused: false,
+            id: None,
+        }
+    }
+
+    /// Sets the focus state. The cursor is only shown while focused.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.is_focused = focused;
+        self
+    }
+
+    /// Makes the field focusable with the given id. The cursor is shown while it has the focus.
+    pub fn focus(mut self, focus: &FocusManager, id: usize) -> Self {
+        self.is_focused = focus.is_focused(id);
+        self.id = Some(id);
+        self
+    }
+}
+
+impl Widget for TextField {
+    fn min_size(&self) -> (u16, u16) {
+        // One extra cell, so the cursor fits behind a full field.
+        (self.length as u16 + 1, 2)
+    }
+
+    fn render(&self, canvas: &mut Canvas<'_>) {
+        if let Some(id) = self.id {
+            canvas.mark(id);
+        }
+        // Unused slots are drawn as underscores, like the in-game naming screen.
+        for x in 0..self.length {
+            let ch = self.chars.get(x).copied().unwrap_or('_');
+            canvas.put(x as u16, 0, ch);
+        }
+        if self.is_focused {
+            canvas.put(self.cursor as u16, 1, '^');
+        }
+    }
+}
+
+/// Creates a TextField widget showing the text and cursor of a `TextInput`.
+///
+/// # Example
+///
+/// ```rust
+/// use widgets::text_field::{text_field, TextInput};
+///
+/// let input = TextInput::new("PIKACHU", 10);
+/// let field = text_field(&input).focused(true);
+/// ```
+pub fn text_field(input: &TextInput) -> TextField {
+    TextField::new(input)
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+/// Checks a traded save before it is written over the save file with the given name.
+///
+/// The buffer is parsed again like a save file and compared with the file on disk: every
+/// sector has to pass its checksum, the save index must not go backwards and the only
+/// change to the Pokemon may be that the party lost the one at `sent` and got `received`
+/// at the end.
+pub fn verify_trade(name: &str, buf: &[u8], sent: usize, received: &StoredPokemon) -> Result<()> {
+    let fail = |reason: String| AppError::VerificationFailed(name.to_string(), reason);
+
+    let original = read_file(&save_file_path(name))?;
+    let original = &original[..original.len().min(SAVE_SIZE)];
+    let before =
+        parse_save(original, name).map_err(|_| fail(tr(Text::VerifyOriginalUnreadable).into()))?;
+    let after = parse_save(buf, name).map_err(|_| fail(tr(Text::VerifyNewUnreadable).into()))?;
+
+    let invalid = invalid_sectors(buf);
+    if !invalid.is_empty() {
+        let ids: Vec<String> = invalid.iter().map(|id| id.to_string()).collect();
+        return Err(fail(trf(Text::VerifyChecksum, &[&ids.join(", ")])));
+    }
+
+    let index = current_save_index(buf).ok_or_else(|| fail(tr(Text::VerifyMixedIndices).into()))?;
+    if current_save_index(original).is_some_and(|original| index < original) {
+        return Err(fail(tr(Text::VerifyIndexBackwards).into()));
+    }
+
+    let before = stored_pokemon(&before.blocks);
+    let after = stored_pokemon(&after.blocks);
+    if before.len() != after.len() {
+        return Err(fail(trf(
+            Text::VerifyPokemonCount,
+            &[&after.len().to_string(), &before.len().to_string()],
+        )));
+    }
+
+    let party = |pokemon: &[StoredPokemon]| -> Vec<StoredPokemon> {
+        pokemon
+            .iter()
+            .filter(|p| matches!(p.location, Location::Party(_)))
+            .cloned()
+            .collect()
+    };
+    let mut expected = party(&before);
+    if sent >= expected.len() {
+        return Err(AppError::PokemonNotFound);
+    }
+    expected.remove(sent);
+    expected.push(received.clone());
+    let party_after = party(&after);
+    // An egg has to arrive as it left: its friendship counts the steps until it hatches
+    // and its original trainer is the one who received it.
+    if let Some(egg) = party_after.last().filter(|_| received.is_egg) {
+        if egg.friendship != received.friendship
+            || egg.ot_id != received.ot_id
+            || egg.ot_name != received.ot_name
+        {
+            return Err(fail(tr(Text::VerifyEggChanged).into()));
+        }
+    }
+    if party_after.len() != expected.len()
+        || !party_after.iter().zip(&expected).all(|(a, b)| a.is_same(b))
+    {
+        return Err(fail(tr(Text::VerifyPartyChanged).into()));
+    }
+
+    let boxed = |pokemon: &[StoredPokemon]| -> Vec<StoredPokemon> {
+        pokemon
+            .iter()
+            .filter(|p| matches!(p.location, Location::Box(_, _)))
+            .cloned()
+            .collect()
+    };
+    let (boxes_before, boxes_after) = (boxed(&before), boxed(&after));
+    let unchanged = boxes_before.len() == boxes_after.len()
+        && boxes_before
+            .iter()
+            .zip(&boxes_after)
+            .all(|(a, b)| a.location == b.location && a.is_same(b));
+    if !unchanged {
+        return Err(fail(tr(Text::VerifyBoxesChanged).into()));
+    }
+
+    Ok(())
+}